@@ -57,6 +57,19 @@ impl FromStr for Asset {
     }
 }
 
+impl serde::Serialize for Asset {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_ref())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Asset {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Asset::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
 pub const SOL: Asset = Asset::Sol;
 pub const SOL_TEST: Asset = Asset::SolTest;
 
@@ -1,64 +1,254 @@
 use {std::sync::mpsc::RecvError, thiserror::Error};
 
+/// Errors returned by this crate.
+///
+/// Every variant carries a stable, machine-readable code, returned by
+/// [`Error::code`] and prefixed to the [`Display`](std::fmt::Display) output
+/// as `[code]`. Codes never change once released, so alerting rules and
+/// downstream services can match on them instead of on messages.
 #[derive(Debug, Error)]
 pub enum Error {
-    #[error(transparent)]
+    #[error("[invalid_pubkey] {0}")]
     PubkeyError(#[from] solana_sdk::pubkey::ParsePubkeyError),
 
-    #[error(transparent)]
+    #[error("[bincode] {0}")]
     BincodeEncodeError(#[from] bincode::Error),
 
-    #[error("Failed to deserialize solana message {0}")]
+    #[error("[invalid_message] Failed to deserialize solana message {0}")]
     InvalidMessage(String),
 
-    #[error("No signature available {0}")]
+    #[error("[no_signature] No signature available {0}")]
     FireblocksNoSig(String),
 
-    #[error("No pubkey for vault {0}")]
+    #[error("[no_pubkey] No pubkey for vault {0}")]
     FireblocksNoPubkey(String),
 
-    #[error(transparent)]
+    #[error("[pubkey_changed] Pubkey changed: {0}")]
+    PubkeyChanged(String),
+
+    #[error("[channel_recv] {0}")]
     ChannelRecvError(#[from] RecvError),
 
-    #[error(transparent)]
+    #[error("[signer] {0}")]
+    SignerError(#[from] solana_sdk::signature::SignerError),
+
+    #[error("[invalid_signature] {0}")]
     SignatureError(#[from] solana_sdk::signature::ParseSignatureError),
 
-    #[error(transparent)]
+    #[error("[signature_mismatch] {0}")]
+    SignatureMismatch(String),
+
+    #[error("[fireblocks_client] {0}")]
     FireblocksClientError(#[from] fireblocks_signer_transport::FireblocksClientError),
 
-    #[error("{0}")]
+    #[error("[create_failed] Creating the Fireblocks transaction failed: {0}")]
+    CreateFailed(fireblocks_signer_transport::FireblocksClientError),
+
+    #[error("[not_created] Fireblocks refused to create the transaction: {0}")]
+    NotCreated(fireblocks_signer_transport::FireblocksClientError),
+
+    #[error("[circuit_open] Fireblocks circuit breaker open: {0}")]
+    CircuitOpen(String),
+
+    #[error("[json_parse] {0}")]
     JsonParseErr(String),
 
-    #[error(transparent)]
+    #[error("[json] {0}")]
     JsonErr(#[from] serde_json::Error),
 
-    #[error("Operation timed out {0}")]
+    #[error("[timeout] Operation timed out {0}")]
     Timeout(String),
 
-    #[error("{0}")]
+    #[error("[shutting_down] Signer is shutting down")]
+    ShuttingDown,
+
+    #[error("[cancelled] Signing was cancelled")]
+    Cancelled,
+
+    #[error("[thread_panic] {0}")]
     ThreadPanic(String),
 
-    #[error("{0}")]
+    #[error("[channel_closed] {0}")]
     ChannelClosed(String),
 
-    #[error("Solan RPC error {0}")]
+    #[error("[address_table] Solan RPC error {0}")]
     ParseAddressTableError(String),
 
-    #[error("Solan RPC Error {0}")]
+    #[error("[solana_rpc] Solan RPC Error {0}")]
     SolanaRpcError(String),
 
-    #[error("pubkey on lookuptable is invalid")]
+    #[error("[invalid_lookup_pubkey] pubkey on lookuptable is invalid")]
     InvalidPubkey,
 
-    #[error("ENV {0} is missing")]
+    #[error("[env_missing] ENV {0} is missing")]
     EnvMissing(String),
 
-    #[error("Unknown asset {0}")]
+    #[error("[unknown_asset] Unknown asset {0}")]
     UnknownAsset(String),
 
-    #[error("Tokio join error: {0}")]
+    #[error("[join] Tokio join error: {0}")]
     JoinError(String),
 
-    #[error(transparent)]
+    #[error("[invalid_signer_pool] Invalid signer pool: {0}")]
+    InvalidSignerPool(String),
+
+    #[error("[unknown_workspace] Unknown workspace {0}")]
+    UnknownWorkspace(String),
+
+    #[error("[misconfigured] Signer misconfigured: {0}")]
+    Misconfigured(String),
+
+    #[error("[webhook_replay] Webhook rejected: {0}")]
+    WebhookReplay(String),
+
+    #[error("[webhook_signature] Webhook signature invalid: {0}")]
+    WebhookSignature(String),
+
+    #[error("[relay_rejected] Transaction not relayed: {0}")]
+    RelayRejected(String),
+
+    #[error("[secret_file] Cannot read secret file {0}")]
+    SecretFile(String),
+
+    #[error("[invalid_secret] Invalid RSA secret: {0}")]
+    InvalidSecret(String),
+
+    #[error("[no_keypair] Signer is backed by Fireblocks and has no local keypair")]
+    NoKeypair,
+
+    #[error("[secret_provider] Cannot fetch credentials: {0}")]
+    SecretProvider(String),
+
+    #[cfg(feature = "keyring")]
+    #[error("[keyring] {0}")]
+    Keyring(#[from] keyring::Error),
+
+    #[cfg(feature = "config")]
+    #[error("[config] {0}")]
     ConfigError(#[from] fireblocks_config::Error),
 }
+
+impl Error {
+    /// The stable, machine-readable code of this error.
+    ///
+    /// ```
+    /// use fireblocks_solana_signer::Error;
+    ///
+    /// let err = Error::Timeout("polling".to_string());
+    /// assert_eq!(err.code(), "timeout");
+    /// assert!(err.to_string().starts_with("[timeout]"));
+    /// ```
+    pub fn code(&self) -> &'static str {
+        match self {
+            Error::PubkeyError(..) => "invalid_pubkey",
+            Error::BincodeEncodeError(..) => "bincode",
+            Error::InvalidMessage(..) => "invalid_message",
+            Error::FireblocksNoSig(..) => "no_signature",
+            Error::FireblocksNoPubkey(..) => "no_pubkey",
+            Error::PubkeyChanged(..) => "pubkey_changed",
+            Error::ChannelRecvError(..) => "channel_recv",
+            Error::SignerError(..) => "signer",
+            Error::SignatureError(..) => "invalid_signature",
+            Error::SignatureMismatch(..) => "signature_mismatch",
+            Error::FireblocksClientError(..) => "fireblocks_client",
+            Error::CreateFailed(..) => "create_failed",
+            Error::NotCreated(..) => "not_created",
+            Error::CircuitOpen(..) => "circuit_open",
+            Error::JsonParseErr(..) => "json_parse",
+            Error::JsonErr(..) => "json",
+            Error::Timeout(..) => "timeout",
+            Error::ShuttingDown => "shutting_down",
+            Error::Cancelled => "cancelled",
+            Error::ThreadPanic(..) => "thread_panic",
+            Error::ChannelClosed(..) => "channel_closed",
+            Error::ParseAddressTableError(..) => "address_table",
+            Error::SolanaRpcError(..) => "solana_rpc",
+            Error::InvalidPubkey => "invalid_lookup_pubkey",
+            Error::EnvMissing(..) => "env_missing",
+            Error::UnknownAsset(..) => "unknown_asset",
+            Error::JoinError(..) => "join",
+            Error::InvalidSignerPool(..) => "invalid_signer_pool",
+            Error::UnknownWorkspace(..) => "unknown_workspace",
+            Error::Misconfigured(..) => "misconfigured",
+            Error::WebhookReplay(..) => "webhook_replay",
+            Error::WebhookSignature(..) => "webhook_signature",
+            Error::RelayRejected(..) => "relay_rejected",
+            Error::SecretFile(..) => "secret_file",
+            Error::InvalidSecret(..) => "invalid_secret",
+            Error::NoKeypair => "no_keypair",
+            Error::SecretProvider(..) => "secret_provider",
+            #[cfg(feature = "keyring")]
+            Error::Keyring(..) => "keyring",
+            #[cfg(feature = "config")]
+            Error::ConfigError(..) => "config",
+        }
+    }
+
+    /// Whether Fireblocks rejected the API credentials, i.e. answered
+    /// `401 Unauthorized` or `403 Forbidden`.
+    pub(crate) fn is_auth(&self) -> bool {
+        let (Error::FireblocksClientError(e) | Error::CreateFailed(e) | Error::NotCreated(e)) =
+            self
+        else {
+            return false;
+        };
+        rejected_credentials(e)
+    }
+}
+
+/// Whether Fireblocks answered `e` with `401 Unauthorized` or
+/// `403 Forbidden`.
+pub(crate) fn rejected_credentials(e: &fireblocks_signer_transport::FireblocksClientError) -> bool {
+    http_error(e).is_some_and(|e| {
+        matches!(
+            e.status(),
+            Some(reqwest::StatusCode::UNAUTHORIZED | reqwest::StatusCode::FORBIDDEN)
+        )
+    })
+}
+
+/// The `reqwest` error behind a transport error, if the transport kept it as
+/// a source.
+fn http_error(e: &fireblocks_signer_transport::FireblocksClientError) -> Option<&reqwest::Error> {
+    std::iter::successors(Some(e as &dyn std::error::Error), |e| e.source())
+        .find_map(|e| e.downcast_ref::<reqwest::Error>())
+}
+
+/// Whether `e` proves the request never created a Fireblocks transaction:
+/// the connection was never established, or Fireblocks answered
+/// `429 Too Many Requests`, `401 Unauthorized` or `403 Forbidden`.
+///
+/// Anything else, a read timeout or a `5xx` included, may have reached
+/// Fireblocks after the transaction was created.
+pub(crate) fn never_created(e: &fireblocks_signer_transport::FireblocksClientError) -> bool {
+    http_error(e).is_some_and(|e| {
+        e.is_connect()
+            || matches!(
+                e.status(),
+                Some(
+                    reqwest::StatusCode::TOO_MANY_REQUESTS
+                        | reqwest::StatusCode::UNAUTHORIZED
+                        | reqwest::StatusCode::FORBIDDEN
+                )
+            )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_code_in_display() {
+        let errors = [
+            Error::InvalidMessage("bad".to_string()),
+            Error::FireblocksNoSig("txid".to_string()),
+            Error::ShuttingDown,
+            Error::InvalidPubkey,
+            Error::EnvMissing("FIREBLOCKS_VAULT".to_string()),
+        ];
+        for e in errors {
+            assert!(e.to_string().starts_with(&format!("[{}] ", e.code())));
+        }
+    }
+}
@@ -0,0 +1,198 @@
+//! C ABI for embedding the signer in non-Rust services, enabled with the `ffi`
+//! feature.
+//!
+//! The exported functions mirror the Rust API closely enough that the
+//! Fireblocks create/poll flow never has to be reimplemented elsewhere:
+//!
+//! | Function                      | Rust equivalent                                  |
+//! |-------------------------------|--------------------------------------------------|
+//! | `fireblocks_signer_from_env`  | [`FireblocksSigner::try_from_env`]               |
+//! | `fireblocks_sign_base64_tx`   | [`FireblocksSigner::sign_transaction_full`]      |
+//! | `fireblocks_get_status`       | current status of a Fireblocks transaction       |
+//!
+//! # Ownership
+//!
+//! - A signer returned by `fireblocks_signer_from_env` must be released with
+//!   `fireblocks_signer_free`.
+//! - Every string returned by this module must be released with
+//!   `fireblocks_string_free`.
+//! - Functions returning a pointer return `NULL` on failure. The error message
+//!   is then available from `fireblocks_last_error` on the same thread until
+//!   the next call into this module.
+
+use {
+    crate::{Error, FireblocksSigner, Result},
+    base64::prelude::*,
+    solana_sdk::transaction::VersionedTransaction,
+    std::{
+        cell::RefCell,
+        ffi::{CStr, CString, c_char},
+        ptr,
+    },
+};
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn set_last_error(err: &Error) {
+    let msg = CString::new(err.to_string().replace('\0', " ")).unwrap_or_default();
+    LAST_ERROR.with(|e| *e.borrow_mut() = Some(msg));
+}
+
+/// Converts `result` into an owned C string, recording the error on failure.
+fn into_c_string(result: Result<String>) -> *mut c_char {
+    LAST_ERROR.with(|e| *e.borrow_mut() = None);
+    match result.and_then(|s| {
+        CString::new(s).map_err(|e| Error::InvalidMessage(format!("interior nul byte: {e}")))
+    }) {
+        Ok(s) => s.into_raw(),
+        Err(e) => {
+            set_last_error(&e);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Reads a UTF-8 argument passed from C.
+///
+/// # Safety
+///
+/// `s` must be `NULL` or a valid nul-terminated string.
+unsafe fn str_arg<'a>(s: *const c_char, name: &str) -> Result<&'a str> {
+    if s.is_null() {
+        return Err(Error::InvalidMessage(format!("{name} is NULL")));
+    }
+    // SAFETY: non-null and nul-terminated per the caller's contract.
+    unsafe { CStr::from_ptr(s) }
+        .to_str()
+        .map_err(|e| Error::InvalidMessage(format!("{name} is not valid UTF-8: {e}")))
+}
+
+/// Returns the signer behind `signer`, or an error if it is `NULL`.
+///
+/// # Safety
+///
+/// `signer` must be `NULL` or a pointer from `fireblocks_signer_from_env`
+/// that has not been freed.
+unsafe fn signer_arg<'a>(signer: *const FireblocksSigner) -> Result<&'a FireblocksSigner> {
+    // SAFETY: valid or null per the caller's contract.
+    unsafe { signer.as_ref() }.ok_or_else(|| Error::InvalidMessage("signer is NULL".to_string()))
+}
+
+/// Creates a signer from the `FIREBLOCKS_*` environment variables.
+///
+/// Returns `NULL` on failure, see `fireblocks_last_error`.
+#[unsafe(no_mangle)]
+pub extern "C" fn fireblocks_signer_from_env() -> *mut FireblocksSigner {
+    LAST_ERROR.with(|e| *e.borrow_mut() = None);
+    match FireblocksSigner::try_from_env(None) {
+        Ok(signer) => Box::into_raw(Box::new(signer)),
+        Err(e) => {
+            set_last_error(&e);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Releases a signer created by `fireblocks_signer_from_env`.
+///
+/// # Safety
+///
+/// `signer` must be `NULL` or a pointer from `fireblocks_signer_from_env`
+/// that has not been freed already.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn fireblocks_signer_free(signer: *mut FireblocksSigner) {
+    if !signer.is_null() {
+        // SAFETY: allocated by `Box::into_raw` in `fireblocks_signer_from_env`.
+        drop(unsafe { Box::from_raw(signer) });
+    }
+}
+
+/// Signs a base64-encoded, bincode-serialized versioned transaction and
+/// returns the fully signed transaction in the same encoding.
+///
+/// Blocks until Fireblocks reaches a final status or the signer's poll
+/// timeout elapses. Returns `NULL` on failure, see `fireblocks_last_error`.
+///
+/// # Safety
+///
+/// `signer` must be a live pointer from `fireblocks_signer_from_env` and
+/// `tx_base64` a valid nul-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn fireblocks_sign_base64_tx(
+    signer: *const FireblocksSigner,
+    tx_base64: *const c_char,
+) -> *mut c_char {
+    // SAFETY: forwarded from the caller's contract.
+    let result = unsafe { signer_arg(signer) }.and_then(|signer| {
+        let encoded = unsafe { str_arg(tx_base64, "tx_base64") }?;
+        let bytes = BASE64_STANDARD
+            .decode(encoded)
+            .map_err(|e| Error::InvalidMessage(format!("invalid base64: {e}")))?;
+        let tx: VersionedTransaction = bincode::deserialize(&bytes)?;
+        let signed = signer.sign_transaction_full(&tx)?;
+        Ok(BASE64_STANDARD.encode(bincode::serialize(&signed)?))
+    });
+    into_c_string(result)
+}
+
+/// Returns the current status of the Fireblocks transaction `fireblocks_id`,
+/// e.g. `PENDING_AUTHORIZATION` or `COMPLETED`.
+///
+/// Returns `NULL` on failure, see `fireblocks_last_error`.
+///
+/// # Safety
+///
+/// `signer` must be a live pointer from `fireblocks_signer_from_env` and
+/// `fireblocks_id` a valid nul-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn fireblocks_get_status(
+    signer: *const FireblocksSigner,
+    fireblocks_id: *const c_char,
+) -> *mut c_char {
+    // SAFETY: forwarded from the caller's contract.
+    let result = unsafe { signer_arg(signer) }.and_then(|signer| {
+        let id = unsafe { str_arg(fireblocks_id, "fireblocks_id") }?;
+        Ok(signer.fetch_status(id)?.status.to_string())
+    });
+    into_c_string(result)
+}
+
+/// Returns the error message of the last failed call on this thread, or
+/// `NULL` if the last call succeeded.
+///
+/// The returned pointer is owned by this module and stays valid until the
+/// next call into it on the same thread. Do not free it.
+#[unsafe(no_mangle)]
+pub extern "C" fn fireblocks_last_error() -> *const c_char {
+    LAST_ERROR.with(|e| e.borrow().as_ref().map_or(ptr::null(), |s| s.as_ptr()))
+}
+
+/// Releases a string returned by this module.
+///
+/// # Safety
+///
+/// `s` must be `NULL` or a string returned by this module that has not been
+/// freed already.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn fireblocks_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        // SAFETY: allocated by `CString::into_raw` in `into_c_string`.
+        drop(unsafe { CString::from_raw(s) });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_errors_are_reported() {
+        let out = unsafe { fireblocks_sign_base64_tx(ptr::null(), ptr::null()) };
+        assert!(out.is_null());
+        let err = unsafe { CStr::from_ptr(fireblocks_last_error()) };
+        assert!(err.to_str().unwrap().contains("signer is NULL"));
+        unsafe { fireblocks_string_free(out) };
+    }
+}
@@ -1,12 +1,37 @@
 #![doc = include_str!("../README.md")]
 mod asset;
+mod client_env;
 mod error;
 mod extensions;
-// mod multi;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod metrics;
+mod multi;
+mod pool;
+mod preview;
+mod read_only;
+mod relayer;
+mod reporting;
+mod secrets;
 mod signer;
+mod status;
+#[cfg(test)]
+mod test_util;
+mod webhook;
+mod workspace;
+/// The complete [`fireblocks_signer_transport`] API, re-exported so it is
+/// always the exact version this crate was built against.
+///
+/// The most commonly used types ([`Client`], [`ClientBuilder`],
+/// [`TransactionResponse`], [`TransactionStatus`], ...) are also available at
+/// the crate root. Depend on this module rather than on
+/// `fireblocks-signer-transport` directly to avoid mismatched versions of the
+/// same types.
+pub use fireblocks_signer_transport as transport;
 use solana_sdk::pubkey::Pubkey;
 pub use {
     asset::*,
+    client_env::{ClientBuilderExt, secret_pem},
     error::Error,
     extensions::*,
     fireblocks_signer_transport::{
@@ -14,21 +39,33 @@ pub use {
         ClientBuilder,
         FIREBLOCKS_API,
         FIREBLOCKS_SANDBOX_API,
+        FireblocksClientError,
         TransactionResponse,
         TransactionStatus,
     },
-    //    multi::*,
+    multi::*,
+    pool::*,
+    preview::*,
+    read_only::*,
+    relayer::*,
+    secrets::*,
     signer::*,
+    status::FailureKind,
     std::str::FromStr,
+    webhook::*,
+    workspace::*,
 };
 
-// pub type DynSigner = dyn multi::MultiSigner;
+/// A type-erased [`MultiSigner`], used to pass heterogeneous signers to the
+/// multi-signature helpers.
+pub type DynSigner = dyn multi::MultiSigner;
 
 /// Environment variables used by the FireblocksSigner.
 #[derive(Debug, Clone, Copy)]
 pub enum EnvVar {
     Vault,
     Secret,
+    SecretPath,
     ApiKey,
     Endpoint,
     Pubkey,
@@ -36,6 +73,10 @@ pub enum EnvVar {
     Devnet,
     PollTimeout,
     PollInterval,
+    PollLog,
+    Broadcast,
+    ClientTimeout,
+    Interactive,
 }
 
 impl std::fmt::Display for EnvVar {
@@ -43,6 +84,7 @@ impl std::fmt::Display for EnvVar {
         let name = match self {
             EnvVar::Vault => "FIREBLOCKS_VAULT",
             EnvVar::Secret => "FIREBLOCKS_SECRET",
+            EnvVar::SecretPath => "FIREBLOCKS_SECRET_PATH",
             EnvVar::ApiKey => "FIREBLOCKS_API_KEY",
             EnvVar::Endpoint => "FIREBLOCKS_ENDPOINT",
             EnvVar::Pubkey => "FIREBLOCKS_PUBKEY",
@@ -50,6 +92,10 @@ impl std::fmt::Display for EnvVar {
             EnvVar::Devnet => "FIREBLOCKS_DEVNET",
             EnvVar::PollTimeout => "FIREBLOCKS_POLL_TIMEOUT",
             EnvVar::PollInterval => "FIREBLOCKS_POLL_INTERVAL",
+            EnvVar::PollLog => "FIREBLOCKS_POLL_LOG",
+            EnvVar::Broadcast => "FIREBLOCKS_BROADCAST",
+            EnvVar::ClientTimeout => "FIREBLOCKS_CLIENT_TIMEOUT",
+            EnvVar::Interactive => "FIREBLOCKS_INTERACTIVE",
         };
         write!(f, "{name}")
     }
@@ -60,6 +106,7 @@ impl AsRef<std::ffi::OsStr> for EnvVar {
         match self {
             EnvVar::Vault => std::ffi::OsStr::new("FIREBLOCKS_VAULT"),
             EnvVar::Secret => std::ffi::OsStr::new("FIREBLOCKS_SECRET"),
+            EnvVar::SecretPath => std::ffi::OsStr::new("FIREBLOCKS_SECRET_PATH"),
             EnvVar::ApiKey => std::ffi::OsStr::new("FIREBLOCKS_API_KEY"),
             EnvVar::Endpoint => std::ffi::OsStr::new("FIREBLOCKS_ENDPOINT"),
             EnvVar::Pubkey => std::ffi::OsStr::new("FIREBLOCKS_PUBKEY"),
@@ -67,6 +114,10 @@ impl AsRef<std::ffi::OsStr> for EnvVar {
             EnvVar::Devnet => std::ffi::OsStr::new("FIREBLOCKS_DEVNET"),
             EnvVar::PollTimeout => std::ffi::OsStr::new("FIREBLOCKS_POLL_TIMEOUT"),
             EnvVar::PollInterval => std::ffi::OsStr::new("FIREBLOCKS_POLL_INTERVAL"),
+            EnvVar::PollLog => std::ffi::OsStr::new("FIREBLOCKS_POLL_LOG"),
+            EnvVar::Broadcast => std::ffi::OsStr::new("FIREBLOCKS_BROADCAST"),
+            EnvVar::ClientTimeout => std::ffi::OsStr::new("FIREBLOCKS_CLIENT_TIMEOUT"),
+            EnvVar::Interactive => std::ffi::OsStr::new("FIREBLOCKS_INTERACTIVE"),
         }
     }
 }
@@ -169,46 +220,58 @@ pub fn build_client_and_address_blocking_safe(
     match address {
         Some(pk) => Ok((client, Pubkey::from_str(&pk)?)),
         None => {
-            let (tx, rx) = std::sync::mpsc::channel();
-            let handle = std::thread::spawn(move || {
-                let result = match client.address(&vault, &asset) {
-                    Err(e) => Err(crate::Error::from(e)),
-                    Ok(pk) => match Pubkey::from_str(&pk) {
-                        Err(e) => Err(crate::Error::from(e)),
-                        Ok(pk) => Ok((client, pk)),
-                    },
-                };
-                // Don't ignore send errors
-                if tx.send(result).is_err() {
-                    tracing::error!("Failed to send result back to main thread");
-                }
-            });
-            tracing::debug!("waiting for client builder response...");
+            let pk = address_blocking_safe(&client, vault, asset)?;
+            Ok((client, pk))
+        }
+    }
+}
+
+/// Looks up the Solana address of `vault` with an existing client, in the
+/// same tokio-safe manner as [`build_client_and_address_blocking_safe`].
+///
+/// # Errors
+///
+/// Fails like [`build_client_and_address_blocking_safe`] when no address is
+/// given.
+pub fn address_blocking_safe(client: &Client, vault: String, asset: Asset) -> Result<Pubkey> {
+    let client = client.clone();
+    let (tx, rx) = std::sync::mpsc::channel();
+    let handle = std::thread::spawn(move || {
+        let result = match metrics::timed(metrics::Endpoint::Address, || {
+            client.address(&vault, &asset)
+        }) {
+            Err(e) => Err(crate::Error::from(e)),
+            Ok(pk) => Pubkey::from_str(&pk).map_err(crate::Error::from),
+        };
+        // Don't ignore send errors
+        if tx.send(result).is_err() {
+            tracing::error!("Failed to send result back to main thread");
+        }
+    });
+    tracing::debug!("waiting for address response...");
 
-            // Add timeout to prevent infinite blocking
-            match rx.recv_timeout(std::time::Duration::from_secs(
-                (DEFAULT_CLIENT_TIMEOUT + 5).into(),
-            )) {
-                Ok(result) => Ok(result?),
-                Err(std::sync::mpsc::RecvTimeoutError::Timeout) => {
-                    tracing::error!("Client initialization timed out");
-                    Err(Error::Timeout(
-                        "Client initialization timed out".to_string(),
-                    ))
-                }
-                Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => {
-                    // Check if thread panicked
-                    if let Err(panic_err) = handle.join() {
-                        tracing::error!("Client initialization thread panicked: {panic_err:?}");
-                        Err(Error::ThreadPanic(
-                            "Client initialization thread panicked".to_string(),
-                        ))
-                    } else {
-                        Err(Error::ChannelClosed(
-                            "Channel disconnected unexpectedly".to_string(),
-                        ))
-                    }
-                }
+    // Add timeout to prevent infinite blocking
+    match rx.recv_timeout(std::time::Duration::from_secs(
+        (DEFAULT_CLIENT_TIMEOUT + 5).into(),
+    )) {
+        Ok(result) => result,
+        Err(std::sync::mpsc::RecvTimeoutError::Timeout) => {
+            tracing::error!("Client initialization timed out");
+            Err(Error::Timeout(
+                "Client initialization timed out".to_string(),
+            ))
+        }
+        Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => {
+            // Check if thread panicked
+            if let Err(panic_err) = handle.join() {
+                tracing::error!("Client initialization thread panicked: {panic_err:?}");
+                Err(Error::ThreadPanic(
+                    "Client initialization thread panicked".to_string(),
+                ))
+            } else {
+                Err(Error::ChannelClosed(
+                    "Channel disconnected unexpectedly".to_string(),
+                ))
             }
         }
     }
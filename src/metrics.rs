@@ -0,0 +1,124 @@
+//! Optional metrics, recorded through the [`metrics`](https://docs.rs/metrics)
+//! facade when the `metrics` feature is enabled.
+//!
+//! | Metric                                | Kind      | Labels                            |
+//! |---------------------------------------|-----------|-----------------------------------|
+//! | `fireblocks_requests_total`           | counter   | `endpoint`, `result`              |
+//! | `fireblocks_request_duration_seconds` | histogram | `endpoint`                        |
+//! | `fireblocks_approval_wait_seconds`    | histogram | `status`                          |
+//! | `fireblocks_transactions_total`       | counter   | `status`                          |
+//! | `fireblocks_created_total`            | counter   | `mode`                            |
+//! | `fireblocks_policy_rejections_total`  | counter   | `status`, `sub_status`, `program` |
+//!
+//! `endpoint` is one of `create` (transaction creation), `get` (a single
+//! transaction status request) and `address` (vault address lookup), so
+//! request latencies only ever measure the Fireblocks API. The time from
+//! creation until a final status, approvals included, is recorded separately
+//! in `fireblocks_approval_wait_seconds`, labelled with that final status.
+//! Comparing the two separates slow API responses from slow approvals. There
+//! is no `cancel` endpoint: the transport client offers no call to cancel a
+//! Fireblocks transaction, and a [`CancelToken`](crate::CancelToken) only
+//! stops local polling. `mode` is `broadcast` or `sign_only`.
+//! `program` is the first program invoked by the transaction, ignoring the
+//! compute budget program, which identifies the caller that tripped a policy.
+//!
+//! Without the feature every function here is a no-op.
+
+use {
+    crate::{TransactionStatus, preview::COMPUTE_BUDGET_PROGRAM},
+    solana_sdk::transaction::VersionedTransaction,
+};
+
+/// The Fireblocks API call being measured.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Endpoint {
+    Create,
+    Get,
+    Address,
+}
+
+impl Endpoint {
+    #[cfg_attr(not(feature = "metrics"), allow(dead_code))]
+    fn as_str(self) -> &'static str {
+        match self {
+            Endpoint::Create => "create",
+            Endpoint::Get => "get",
+            Endpoint::Address => "address",
+        }
+    }
+}
+
+/// Runs `f`, recording its latency and outcome against `endpoint`.
+pub(crate) fn timed<T, E>(
+    endpoint: Endpoint,
+    f: impl FnOnce() -> std::result::Result<T, E>,
+) -> std::result::Result<T, E> {
+    #[cfg(feature = "metrics")]
+    {
+        let start = std::time::Instant::now();
+        let result = f();
+        let outcome = if result.is_ok() { "ok" } else { "error" };
+        metrics::histogram!("fireblocks_request_duration_seconds", "endpoint" => endpoint.as_str())
+            .record(start.elapsed().as_secs_f64());
+        metrics::counter!("fireblocks_requests_total", "endpoint" => endpoint.as_str(), "result" => outcome)
+            .increment(1);
+        result
+    }
+    #[cfg(not(feature = "metrics"))]
+    {
+        let _ = endpoint;
+        f()
+    }
+}
+
+/// Records how long a transaction took from creation to its final `status`.
+pub(crate) fn approval_wait(waited: std::time::Duration, status: &TransactionStatus) {
+    #[cfg(feature = "metrics")]
+    metrics::histogram!("fireblocks_approval_wait_seconds", "status" => status.to_string())
+        .record(waited.as_secs_f64());
+    #[cfg(not(feature = "metrics"))]
+    let _ = (waited, status);
+}
+
+/// Counts a transaction that reached `status` after polling.
+pub(crate) fn final_status(status: &TransactionStatus) {
+    #[cfg(feature = "metrics")]
+    metrics::counter!("fireblocks_transactions_total", "status" => status.to_string()).increment(1);
+    #[cfg(not(feature = "metrics"))]
+    let _ = status;
+}
+
+/// Counts a transaction created at Fireblocks, by broadcast mode.
+pub(crate) fn created(broadcast: bool) {
+    #[cfg(feature = "metrics")]
+    metrics::counter!("fireblocks_created_total", "mode" => if broadcast { "broadcast" } else { "sign_only" })
+        .increment(1);
+    #[cfg(not(feature = "metrics"))]
+    let _ = broadcast;
+}
+
+/// Counts a transaction that ended `BLOCKED` by policy or `REJECTED`.
+pub(crate) fn policy_rejection(status: &TransactionStatus, sub_status: &str, program: &str) {
+    #[cfg(feature = "metrics")]
+    metrics::counter!(
+        "fireblocks_policy_rejections_total",
+        "status" => status.to_string(),
+        "sub_status" => sub_status.to_string(),
+        "program" => program.to_string()
+    )
+    .increment(1);
+    #[cfg(not(feature = "metrics"))]
+    let _ = (status, sub_status, program);
+}
+
+/// The `program` label of `tx`: its first top-level program other than the
+/// compute budget program.
+pub(crate) fn program(tx: &VersionedTransaction) -> String {
+    let keys = tx.message.static_account_keys();
+    tx.message
+        .instructions()
+        .iter()
+        .filter_map(|ix| keys.get(usize::from(ix.program_id_index)))
+        .find(|program| **program != COMPUTE_BUDGET_PROGRAM)
+        .map_or_else(|| "none".to_string(), ToString::to_string)
+}
@@ -1,5 +1,5 @@
 use {
-    crate::{DynSigner, FireblocksSigner, VersionedTransactionExtension},
+    crate::{DynSigner, FireblocksSigner},
     solana_sdk::{
         hash::Hash,
         signature::{Signer, SignerError},
@@ -76,7 +76,6 @@ macro_rules! impl_default_multi_signer {
 // Implement default multi-sig behavior for standard Solana signer types
 impl_default_multi_signer!(solana_sdk::signature::Keypair);
 impl_default_multi_signer!(solana_sdk::signature::Presigner);
-impl_default_multi_signer!(solana_remote_wallet::remote_keypair::RemoteKeypair);
 impl_default_multi_signer!(solana_sdk::signature::null_signer::NullSigner);
 
 impl MultiSigner for FireblocksSigner {
@@ -88,7 +87,7 @@ impl MultiSigner for FireblocksSigner {
     ) -> Result<(), SignerError> {
         info!(
             "multi signing: {} other signer(s) plus FireblocksSigner",
-            all_signers.len() - 1
+            all_signers.len().saturating_sub(1)
         );
         // Sign with all other signers first
         for signer in all_signers {
@@ -97,29 +96,11 @@ impl MultiSigner for FireblocksSigner {
             }
         }
 
-        let vtx: VersionedTransaction = tx.clone().into();
-        let sig = self
-            .sign_versioned_transaction(&vtx)
+        // Sign with Fireblocks, keeping the signatures collected above
+        let mut vtx: VersionedTransaction = tx.clone().into();
+        self.sign_in_place(&mut vtx)
             .map_err(|e| SignerError::Custom(e.to_string()))?;
-
-        // Find position and insert Fireblocks signature
-        let positions = tx.get_signing_keypair_positions(&[self.pubkey()])?;
-        match positions.first() {
-            Some(Some(pos)) => {
-                tracing::debug!("using slot {} for fireblocks sig {sig}", *pos);
-                tx.signatures[*pos] = sig;
-            }
-            Some(None) => {
-                return Err(SignerError::Custom(
-                    "Fireblocks pubkey not found in transaction's required signers".to_string(),
-                ));
-            }
-            None => {
-                return Err(SignerError::Custom(
-                    "Failed to get signing positions from transaction".to_string(),
-                ));
-            }
-        }
+        tx.signatures = vtx.signatures;
         Ok(())
     }
 
@@ -137,29 +118,9 @@ impl MultiSigner for FireblocksSigner {
         }
 
         // Sign with Fireblocks using the partially-signed transaction
-        let sig = self
-            .sign_versioned_transaction(tx)
-            .map_err(|e| SignerError::Custom(e.to_string()))?;
-
-        // Find position and insert signature
-        let positions = tx.get_signing_keypair_positions(&[self.pubkey()])?;
-        match positions.first() {
-            Some(Some(pos)) => {
-                tracing::debug!("using slot {} for fireblocks sig {sig}", *pos);
-                tx.signatures[*pos] = sig;
-            }
-            Some(None) => {
-                return Err(SignerError::Custom(
-                    "Fireblocks pubkey not found in transaction's required signers".to_string(),
-                ));
-            }
-            None => {
-                return Err(SignerError::Custom(
-                    "Failed to get signing positions from transaction".to_string(),
-                ));
-            }
-        }
-        Ok(())
+        self.sign_in_place(tx)
+            .map(|_| ())
+            .map_err(|e| SignerError::Custom(e.to_string()))
     }
 }
 
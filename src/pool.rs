@@ -0,0 +1,143 @@
+//! Round-robin pooling of Fireblocks signers.
+//!
+//! Fireblocks rate limits are applied per API user. A [`SignerPool`] spreads
+//! signing requests across several [`FireblocksSigner`]s that share a vault
+//! (and therefore a pubkey) but authenticate as different API users, failing
+//! over to the next member when one is throttled or disabled.
+
+use {
+    crate::{Error, FireblocksSigner, Result},
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Signature, Signer, SignerError},
+        transaction::VersionedTransaction,
+    },
+    std::sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// A pool of [`FireblocksSigner`]s for the same vault, used in round-robin
+/// order.
+///
+/// Each sign request starts at the next member in rotation. If that member
+/// provably created nothing, because Fireblocks refused the request
+/// ([`Error::NotCreated`], e.g. `429 Too Many Requests` or a disabled API
+/// user) or its circuit breaker is open ([`Error::CircuitOpen`]), the request
+/// is retried on the following member until every member has been tried.
+/// Every other error is returned immediately. That includes a create that
+/// timed out or got a `5xx` ([`Error::CreateFailed`]), since Fireblocks may
+/// have created the transaction anyway: another member would create a second
+/// Fireblocks transaction for the same payload.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fireblocks_solana_signer::{FireblocksSigner, SignerPool};
+///
+/// # fn main() -> anyhow::Result<()> {
+/// let primary = FireblocksSigner::try_from_config(&["primary"], |t| tracing::info!("{t}"))?;
+/// let secondary = FireblocksSigner::try_from_config(&["secondary"], |t| tracing::info!("{t}"))?;
+/// let pool = SignerPool::new(vec![primary, secondary])?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct SignerPool {
+    signers: Vec<FireblocksSigner>,
+    next: AtomicUsize,
+}
+
+impl SignerPool {
+    /// Creates a pool from signers that all resolve to the same pubkey.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidSignerPool`] if `signers` is empty or the
+    /// signers do not share a pubkey.
+    pub fn new(signers: Vec<FireblocksSigner>) -> Result<Self> {
+        let Some(first) = signers.first() else {
+            return Err(Error::InvalidSignerPool(
+                "at least one signer is required".to_string(),
+            ));
+        };
+        if let Some(other) = signers.iter().find(|s| s.pk != first.pk) {
+            return Err(Error::InvalidSignerPool(format!(
+                "signers must share a pubkey, found {} and {}",
+                first.pk, other.pk
+            )));
+        }
+        Ok(Self {
+            signers,
+            next: AtomicUsize::new(0),
+        })
+    }
+
+    /// The signers in this pool.
+    pub fn signers(&self) -> &[FireblocksSigner] {
+        &self.signers
+    }
+
+    /// Signs a versioned transaction with the next signer in rotation,
+    /// failing over to the remaining signers while Fireblocks refuses to
+    /// create the transaction.
+    ///
+    /// See [`FireblocksSigner::sign_versioned_transaction`].
+    pub fn sign_versioned_transaction(&self, tx: &VersionedTransaction) -> Result<Signature> {
+        self.with_failover(|signer| signer.sign_versioned_transaction(tx))
+    }
+
+    fn with_failover<F>(&self, f: F) -> Result<Signature>
+    where
+        F: Fn(&FireblocksSigner) -> Result<Signature>,
+    {
+        let len = self.signers.len();
+        let start = self.next.fetch_add(1, Ordering::Relaxed) % len;
+        let mut last_err = None;
+        for i in 0..len {
+            let signer = &self.signers[(start + i) % len];
+            match f(signer) {
+                Ok(sig) => return Ok(sig),
+                Err(e @ (Error::NotCreated(_) | Error::CircuitOpen(_))) => {
+                    tracing::warn!("{signer:?} failed, trying next signer in pool: {e}");
+                    last_err = Some(e);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Err(last_err
+            .unwrap_or_else(|| Error::InvalidSignerPool("no signer available in pool".to_string())))
+    }
+}
+
+impl Signer for SignerPool {
+    fn try_pubkey(&self) -> std::result::Result<Pubkey, SignerError> {
+        Ok(self.signers[0].pk)
+    }
+
+    fn try_sign_message(&self, message: &[u8]) -> std::result::Result<Signature, SignerError> {
+        self.with_failover(|signer| signer.sign_message_detached(message))
+            .map_err(|e| SignerError::Custom(format!("{e}")))
+    }
+
+    fn is_interactive(&self) -> bool {
+        self.signers[0].is_interactive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::test_util::remote_signer};
+
+    #[test]
+    fn test_pool_validation() -> anyhow::Result<()> {
+        assert!(SignerPool::new(vec![]).is_err());
+
+        let pk = Pubkey::new_unique();
+        let mismatched = vec![remote_signer(pk), remote_signer(Pubkey::new_unique())];
+        assert!(SignerPool::new(mismatched).is_err());
+
+        let pool = SignerPool::new(vec![remote_signer(pk), remote_signer(pk)])?;
+        assert_eq!(pool.pubkey(), pk);
+        assert_eq!(pool.signers().len(), 2);
+        Ok(())
+    }
+}
@@ -0,0 +1,337 @@
+//! Human-readable previews of Solana messages.
+//!
+//! [`preview`] breaks a [`VersionedMessage`] down into its accounts, programs
+//! and instructions, decoding the common ones (system transfers, memos and
+//! compute budget settings) and summing the lamport movements that can be
+//! known without fetching any account. Approval UIs and logs can render it
+//! instead of an opaque base64 blob.
+
+use {
+    solana_sdk::{message::VersionedMessage, pubkey::Pubkey},
+    std::fmt,
+};
+
+pub(crate) const SYSTEM_PROGRAM: Pubkey =
+    Pubkey::from_str_const("11111111111111111111111111111111");
+pub(crate) const COMPUTE_BUDGET_PROGRAM: Pubkey =
+    Pubkey::from_str_const("ComputeBudget111111111111111111111111111111");
+const MEMO_PROGRAMS: [Pubkey; 2] = [
+    Pubkey::from_str_const("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"),
+    Pubkey::from_str_const("Memo1UhkJRfHyvLMcVucJwxXeuD728EqVDDwQDxFMNo"),
+];
+const LAMPORTS_PER_SIGNATURE: u64 = 5_000;
+
+/// A structured, human-readable breakdown of a message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionPreview {
+    /// The account paying the fees.
+    pub fee_payer: Pubkey,
+
+    /// The statically known accounts, in message order.
+    pub accounts: Vec<AccountPreview>,
+
+    /// How many accounts are loaded from address lookup tables. Those are
+    /// not resolved, since that requires fetching the tables.
+    pub lookup_accounts: usize,
+
+    /// Every program invoked by a top-level instruction, without duplicates.
+    pub programs: Vec<Pubkey>,
+
+    /// The top-level instructions, in execution order.
+    pub instructions: Vec<InstructionPreview>,
+
+    /// Net lamport changes known from the message alone: decoded transfers
+    /// and the fee (base fee plus any priority fee) charged to the fee payer.
+    pub lamport_deltas: Vec<(Pubkey, i128)>,
+}
+
+/// An account of the message and its permissions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccountPreview {
+    pub pubkey: Pubkey,
+    pub signer: bool,
+    pub writable: bool,
+}
+
+/// A top-level instruction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InstructionPreview {
+    pub program: Pubkey,
+
+    /// The instruction's accounts, `None` for accounts loaded from a lookup
+    /// table.
+    pub accounts: Vec<Option<Pubkey>>,
+
+    pub decoded: DecodedInstruction,
+}
+
+/// The decoded form of a common instruction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DecodedInstruction {
+    /// A system program transfer.
+    Transfer {
+        from: Option<Pubkey>,
+        to: Option<Pubkey>,
+        lamports: u64,
+    },
+    /// A memo, lossily decoded as UTF-8.
+    Memo(String),
+    /// A compute unit limit.
+    ComputeUnitLimit(u32),
+    /// A compute unit price in micro-lamports.
+    ComputeUnitPrice(u64),
+    /// Any instruction not decoded by this module.
+    Unknown { data_len: usize },
+}
+
+/// Builds a [`TransactionPreview`] of `message`.
+///
+/// # Examples
+///
+/// ```
+/// use {
+///     fireblocks_solana_signer::preview,
+///     solana_sdk::{
+///         instruction::Instruction,
+///         message::{Message, VersionedMessage},
+///         pubkey::Pubkey,
+///     },
+/// };
+///
+/// let payer = Pubkey::new_unique();
+/// let memo = Instruction {
+///     program_id: spl_memo_interface::v3::id(),
+///     accounts: vec![],
+///     data: b"payroll".to_vec(),
+/// };
+/// let message = VersionedMessage::Legacy(Message::new(&[memo], Some(&payer)));
+/// let preview = preview(&message);
+/// assert_eq!(preview.fee_payer, payer);
+/// println!("{preview}");
+/// ```
+pub fn preview(message: &VersionedMessage) -> TransactionPreview {
+    let keys = message.static_account_keys();
+    let header = message.header();
+    let signers = usize::from(header.num_required_signatures);
+    let writable_signers = signers.saturating_sub(usize::from(header.num_readonly_signed_accounts));
+    let writable_unsigned = keys
+        .len()
+        .saturating_sub(usize::from(header.num_readonly_unsigned_accounts));
+    let accounts = keys
+        .iter()
+        .enumerate()
+        .map(|(i, pubkey)| AccountPreview {
+            pubkey: *pubkey,
+            signer: i < signers,
+            writable: if i < signers {
+                i < writable_signers
+            } else {
+                i < writable_unsigned
+            },
+        })
+        .collect();
+    let lookup_accounts = message.address_table_lookups().map_or(0, |lookups| {
+        lookups
+            .iter()
+            .map(|l| l.writable_indexes.len() + l.readonly_indexes.len())
+            .sum()
+    });
+
+    let mut programs: Vec<Pubkey> = Vec::new();
+    let instructions: Vec<InstructionPreview> = message
+        .instructions()
+        .iter()
+        .map(|ix| {
+            let program = keys
+                .get(usize::from(ix.program_id_index))
+                .copied()
+                .unwrap_or_default();
+            if !programs.contains(&program) {
+                programs.push(program);
+            }
+            let accounts: Vec<Option<Pubkey>> = ix
+                .accounts
+                .iter()
+                .map(|i| keys.get(usize::from(*i)).copied())
+                .collect();
+            let decoded = decode(&program, &accounts, &ix.data);
+            InstructionPreview {
+                program,
+                accounts,
+                decoded,
+            }
+        })
+        .collect();
+
+    let fee_payer = keys.first().copied().unwrap_or_default();
+    let mut lamport_deltas: Vec<(Pubkey, i128)> = Vec::new();
+    let mut apply =
+        |pubkey: Pubkey, delta: i128| match lamport_deltas.iter_mut().find(|(k, _)| *k == pubkey) {
+            Some((_, total)) => *total += delta,
+            None => lamport_deltas.push((pubkey, delta)),
+        };
+    apply(fee_payer, -i128::from(fee(signers, &instructions)));
+    for ix in &instructions {
+        if let DecodedInstruction::Transfer {
+            from: Some(from),
+            to: Some(to),
+            lamports,
+        } = ix.decoded
+        {
+            apply(from, -i128::from(lamports));
+            apply(to, i128::from(lamports));
+        }
+    }
+
+    TransactionPreview {
+        fee_payer,
+        accounts,
+        lookup_accounts,
+        programs,
+        instructions,
+        lamport_deltas,
+    }
+}
+
+fn decode(program: &Pubkey, accounts: &[Option<Pubkey>], data: &[u8]) -> DecodedInstruction {
+    let unknown = DecodedInstruction::Unknown {
+        data_len: data.len(),
+    };
+    if *program == SYSTEM_PROGRAM {
+        // bincode: u32 variant index 2 (Transfer) followed by u64 lamports
+        return match (data.get(..4), data.get(4..12)) {
+            (Some([2, 0, 0, 0]), Some(lamports)) if data.len() == 12 && accounts.len() >= 2 => {
+                DecodedInstruction::Transfer {
+                    from: accounts[0],
+                    to: accounts[1],
+                    lamports: u64::from_le_bytes(lamports.try_into().unwrap_or_default()),
+                }
+            }
+            _ => unknown,
+        };
+    }
+    if *program == COMPUTE_BUDGET_PROGRAM {
+        return match data {
+            [2, rest @ ..] if rest.len() == 4 => DecodedInstruction::ComputeUnitLimit(
+                u32::from_le_bytes(rest.try_into().unwrap_or_default()),
+            ),
+            [3, rest @ ..] if rest.len() == 8 => DecodedInstruction::ComputeUnitPrice(
+                u64::from_le_bytes(rest.try_into().unwrap_or_default()),
+            ),
+            _ => unknown,
+        };
+    }
+    if MEMO_PROGRAMS.contains(program) {
+        return DecodedInstruction::Memo(String::from_utf8_lossy(data).into_owned());
+    }
+    unknown
+}
+
+/// The base fee, plus the priority fee if both a compute unit limit and
+/// price are set.
+fn fee(signatures: usize, instructions: &[InstructionPreview]) -> u64 {
+    let base = LAMPORTS_PER_SIGNATURE.saturating_mul(signatures as u64);
+    let limit = instructions.iter().find_map(|ix| match ix.decoded {
+        DecodedInstruction::ComputeUnitLimit(limit) => Some(u128::from(limit)),
+        _ => None,
+    });
+    let price = instructions.iter().find_map(|ix| match ix.decoded {
+        DecodedInstruction::ComputeUnitPrice(price) => Some(u128::from(price)),
+        _ => None,
+    });
+    let priority = match (limit, price) {
+        (Some(limit), Some(price)) => (limit * price).div_ceil(1_000_000),
+        _ => 0,
+    };
+    base.saturating_add(u64::try_from(priority).unwrap_or(u64::MAX))
+}
+
+impl fmt::Display for TransactionPreview {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "fee payer: {}", self.fee_payer)?;
+        writeln!(f, "accounts:")?;
+        for account in &self.accounts {
+            let flags = match (account.signer, account.writable) {
+                (true, true) => "signer, writable",
+                (true, false) => "signer",
+                (false, true) => "writable",
+                (false, false) => "readonly",
+            };
+            writeln!(f, "  {} ({flags})", account.pubkey)?;
+        }
+        if self.lookup_accounts > 0 {
+            writeln!(f, "  + {} from lookup tables", self.lookup_accounts)?;
+        }
+        writeln!(f, "instructions:")?;
+        for (i, ix) in self.instructions.iter().enumerate() {
+            write!(f, "  #{i} {}: ", ix.program)?;
+            match &ix.decoded {
+                DecodedInstruction::Transfer { from, to, lamports } => {
+                    let show =
+                        |k: &Option<Pubkey>| k.map_or("<lookup>".to_string(), |k| k.to_string());
+                    writeln!(
+                        f,
+                        "transfer {lamports} lamports {} -> {}",
+                        show(from),
+                        show(to)
+                    )?;
+                }
+                DecodedInstruction::Memo(memo) => writeln!(f, "memo {memo:?}")?,
+                DecodedInstruction::ComputeUnitLimit(limit) => {
+                    writeln!(f, "compute unit limit {limit}")?
+                }
+                DecodedInstruction::ComputeUnitPrice(price) => {
+                    writeln!(f, "compute unit price {price} micro-lamports")?
+                }
+                DecodedInstruction::Unknown { data_len } => writeln!(
+                    f,
+                    "{} account(s), {data_len} byte(s) of data",
+                    ix.accounts.len()
+                )?,
+            }
+        }
+        writeln!(f, "lamport changes:")?;
+        for (pubkey, delta) in &self.lamport_deltas {
+            writeln!(f, "  {pubkey}: {delta:+}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        solana_sdk::{hash::Hash, message::Message},
+    };
+
+    #[test]
+    fn test_preview_transfer() {
+        let from = Pubkey::new_unique();
+        let to = Pubkey::new_unique();
+        let transfer = solana_system_interface::instruction::transfer(&from, &to, 42);
+        let message = VersionedMessage::Legacy(Message::new_with_blockhash(
+            &[transfer],
+            Some(&from),
+            &Hash::default(),
+        ));
+
+        let preview = preview(&message);
+        assert_eq!(preview.fee_payer, from);
+        assert_eq!(preview.programs, vec![SYSTEM_PROGRAM]);
+        assert_eq!(
+            preview.instructions[0].decoded,
+            DecodedInstruction::Transfer {
+                from: Some(from),
+                to: Some(to),
+                lamports: 42,
+            }
+        );
+        assert_eq!(preview.lamport_deltas, vec![
+            (from, -42 - i128::from(LAMPORTS_PER_SIGNATURE)),
+            (to, 42)
+        ]);
+        assert!(preview.accounts[0].signer && preview.accounts[0].writable);
+        assert!(!preview.accounts[1].signer && preview.accounts[1].writable);
+    }
+}
@@ -0,0 +1,192 @@
+//! A Fireblocks client restricted to read-only calls.
+
+use {
+    crate::{
+        Asset,
+        Client,
+        ClientBuilder,
+        Error,
+        Result,
+        TransactionResponse,
+        metrics::{self, Endpoint},
+    },
+    solana_sdk::pubkey::Pubkey,
+    std::time::Duration,
+};
+
+/// A Fireblocks client that can only query, never create, transactions.
+///
+/// The wrapped [`Client`] is not accessible, so code holding a
+/// `ReadOnlyClient` (e.g. a monitoring service) structurally cannot submit
+/// anything to Fireblocks. Pair it with an API user that has a viewer role to
+/// enforce the same on the Fireblocks side.
+///
+/// Every call runs on a dedicated OS thread and is safe to use from a tokio
+/// runtime.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fireblocks_solana_signer::{ClientBuilder, FIREBLOCKS_API, ReadOnlyClient, SOL};
+///
+/// # fn main() -> anyhow::Result<()> {
+/// let client = ReadOnlyClient::from_builder(
+///     ClientBuilder::new("viewer-api-key", b"viewer-pem").with_url(FIREBLOCKS_API),
+/// )?;
+/// let pk = client.address("0", SOL)?;
+/// let tx = client.transaction("fireblocks-transaction-id")?;
+/// println!("{pk}: {}", tx.status);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct ReadOnlyClient {
+    client: Client,
+}
+
+impl std::fmt::Debug for ReadOnlyClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ReadOnlyClient")
+    }
+}
+
+impl From<Client> for ReadOnlyClient {
+    fn from(client: Client) -> Self {
+        Self { client }
+    }
+}
+
+impl ReadOnlyClient {
+    /// Builds the underlying client in a tokio-safe manner.
+    ///
+    /// See [`build_client_safe`](crate::build_client_safe).
+    pub fn from_builder(builder: ClientBuilder) -> Result<Self> {
+        Ok(crate::build_client_safe(builder)?.into())
+    }
+
+    /// The Solana address of `vault_id`.
+    pub fn address(&self, vault_id: impl Into<String>, asset: Asset) -> Result<Pubkey> {
+        crate::address_blocking_safe(&self.client, vault_id.into(), asset)
+    }
+
+    /// The current state of the Fireblocks transaction `id`, without waiting
+    /// for it to reach a final status.
+    pub fn transaction(&self, id: &str) -> Result<TransactionResponse> {
+        TransactionReader::transaction(self, id)
+    }
+}
+
+/// Read-only access to Fireblocks transactions.
+///
+/// Write monitoring code against `impl TransactionReader` to have the
+/// compiler guarantee it cannot submit anything; both [`Client`] and
+/// [`ReadOnlyClient`] implement it. Listing transactions is not offered by
+/// the transport crate yet.
+pub trait TransactionReader {
+    /// The current state of the Fireblocks transaction `id`, without waiting
+    /// for it to reach a final status.
+    fn transaction(&self, id: &str) -> Result<TransactionResponse> {
+        self.poll(id, Duration::ZERO, Duration::ZERO, |_| {})
+            .map(|(response, _)| response)
+    }
+
+    /// Polls the transaction `id` every `interval` until it reaches a final
+    /// status or `timeout` elapses, calling `callback` with every response.
+    ///
+    /// Returns the last response and the signature, if any.
+    fn poll(
+        &self,
+        id: &str,
+        timeout: Duration,
+        interval: Duration,
+        callback: fn(&TransactionResponse),
+    ) -> Result<(TransactionResponse, Option<String>)>;
+}
+
+/// Creation of Fireblocks transactions, the capability a [`ReadOnlyClient`]
+/// lacks.
+pub trait TransactionSubmitter: TransactionReader {
+    /// Has Fireblocks sign and broadcast `transaction_base64`, returning the
+    /// Fireblocks transaction id.
+    fn program_call(
+        &self,
+        asset: &Asset,
+        vault_id: &str,
+        transaction_base64: String,
+    ) -> Result<String>;
+
+    /// Has Fireblocks sign `transaction_base64` without broadcasting it,
+    /// returning the Fireblocks transaction id.
+    fn sign_only(
+        &self,
+        asset: &Asset,
+        vault_id: &str,
+        transaction_base64: String,
+    ) -> Result<String>;
+}
+
+impl TransactionReader for Client {
+    fn poll(
+        &self,
+        id: &str,
+        timeout: Duration,
+        interval: Duration,
+        callback: fn(&TransactionResponse),
+    ) -> Result<(TransactionResponse, Option<String>)> {
+        if timeout.is_zero() {
+            return Ok(metrics::timed(Endpoint::Get, || {
+                Client::poll(self, id, timeout, interval, callback)
+            })?);
+        }
+        let start = std::time::Instant::now();
+        let polled = Client::poll(self, id, timeout, interval, callback)?;
+        metrics::approval_wait(start.elapsed(), &polled.0.status);
+        Ok(polled)
+    }
+}
+
+impl TransactionSubmitter for Client {
+    fn program_call(
+        &self,
+        asset: &Asset,
+        vault_id: &str,
+        transaction_base64: String,
+    ) -> Result<String> {
+        let resp = metrics::timed(Endpoint::Create, || {
+            Client::program_call(self, asset, vault_id, transaction_base64)
+        })?;
+        Ok(resp.id)
+    }
+
+    fn sign_only(
+        &self,
+        asset: &Asset,
+        vault_id: &str,
+        transaction_base64: String,
+    ) -> Result<String> {
+        let resp = metrics::timed(Endpoint::Create, || {
+            Client::sign_only(self, asset, vault_id, transaction_base64)
+        })?;
+        Ok(resp.id)
+    }
+}
+
+impl TransactionReader for ReadOnlyClient {
+    fn poll(
+        &self,
+        id: &str,
+        timeout: Duration,
+        interval: Duration,
+        callback: fn(&TransactionResponse),
+    ) -> Result<(TransactionResponse, Option<String>)> {
+        let client = self.client.clone();
+        let id = id.to_string();
+        let (tx, rx) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            let _ = tx.send(TransactionReader::poll(
+                &client, &id, timeout, interval, callback,
+            ));
+        });
+        rx.recv()?
+    }
+}
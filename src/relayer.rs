@@ -0,0 +1,301 @@
+//! Fee-payer relaying for gasless user flows.
+//!
+//! A [`Relayer`] lets a Fireblocks vault pay the fees of transactions built
+//! and signed by end users. The vault signs nothing but the fee payer slot,
+//! and every transaction is checked before it is sent to Fireblocks.
+
+use {
+    crate::{Error, FireblocksSigner, Result, SigningReceipt},
+    solana_sdk::{
+        instruction::CompiledInstruction,
+        pubkey::{self, Pubkey},
+        transaction::VersionedTransaction,
+    },
+};
+
+const COMPUTE_BUDGET_PROGRAM: Pubkey = pubkey!("ComputeBudget111111111111111111111111111111");
+const LAMPORTS_PER_SIGNATURE: u64 = 5_000;
+const DEFAULT_UNITS_PER_INSTRUCTION: u64 = 200_000;
+const MAX_UNITS: u64 = 1_400_000;
+const MICRO_LAMPORTS_PER_LAMPORT: u128 = 1_000_000;
+
+/// Checks user-submitted transactions and co-signs them as fee payer.
+///
+/// A transaction is relayed only if:
+///
+/// - the vault is its fee payer (the first account),
+/// - the vault appears in no instruction, so its lamports cannot be moved
+///   beyond the fee,
+/// - every program it invokes is in the allowlist,
+/// - it needs at most `max_signatures` signatures, which bounds the fee,
+/// - its fee, including any priority fee set through the compute budget
+///   program, is at most `max_fee_lamports` when set, and
+/// - every signature other than the vault's is present and valid.
+///
+/// The vault always signs sign-only, whatever the signer's
+/// [`broadcast`](FireblocksSigner::broadcast) flag, so submit the returned
+/// transaction yourself.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fireblocks_solana_signer::{FireblocksSigner, Relayer};
+///
+/// # fn relay(user_tx: solana_sdk::transaction::VersionedTransaction) -> anyhow::Result<()> {
+/// let relayer = Relayer::builder()
+///     .signer(FireblocksSigner::try_from_env(None)?)
+///     .allowed_programs(vec![spl_memo_interface::v3::id()])
+///     .build();
+/// let (signed, receipt) = relayer.relay(user_tx)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, bon::Builder)]
+pub struct Relayer {
+    /// The signer of the fee-paying vault.
+    signer: FireblocksSigner,
+
+    /// Programs user transactions may invoke.
+    allowed_programs: Vec<Pubkey>,
+
+    /// The most signatures, including the fee payer's, a relayed transaction
+    /// may require.
+    #[builder(default = 2)]
+    max_signatures: usize,
+
+    /// The most lamports a relayed transaction may cost the vault in fees:
+    /// the signature fee plus the priority fee requested through the compute
+    /// budget program. Defaults to `None`, where only `max_signatures`
+    /// bounds the fee, which is enough unless the compute budget program is
+    /// allowed.
+    max_fee_lamports: Option<u64>,
+}
+
+impl Relayer {
+    /// The signer of the fee-paying vault.
+    pub fn signer(&self) -> &FireblocksSigner {
+        &self.signer
+    }
+
+    /// Checks `tx` against the relay rules without signing it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::RelayRejected`] naming the first rule `tx` breaks.
+    pub fn validate(&self, tx: &VersionedTransaction) -> Result<()> {
+        tx.sanitize()
+            .map_err(|e| reject(format!("malformed transaction: {e}")))?;
+        let vault = self.signer.pk;
+        let message = &tx.message;
+        let keys = message.static_account_keys();
+        if keys.first() != Some(&vault) {
+            return Err(reject(format!("fee payer must be the vault {vault}")));
+        }
+        let required = usize::from(message.header().num_required_signatures);
+        if required > self.max_signatures {
+            return Err(reject(format!(
+                "{required} signatures required, at most {} allowed",
+                self.max_signatures
+            )));
+        }
+        for ix in message.instructions() {
+            let program = keys
+                .get(usize::from(ix.program_id_index))
+                .ok_or_else(|| reject("instruction references a missing program".to_string()))?;
+            if !self.allowed_programs.contains(program) {
+                return Err(reject(format!("program {program} is not allowed")));
+            }
+            // Account 0 is the vault. Moving its funds needs its signature and
+            // signers are always static keys, so index 0 covers every use.
+            if ix.accounts.contains(&0) {
+                return Err(reject(format!(
+                    "instruction for {program} references the vault"
+                )));
+            }
+        }
+        if let Some(max) = self.max_fee_lamports {
+            let fee = fee_lamports(tx)?;
+            if fee > max {
+                return Err(reject(format!("fee of {fee} lamports exceeds {max}")));
+            }
+        }
+        if tx.signatures.len() != required {
+            return Err(reject(format!(
+                "{} signatures present, {required} required",
+                tx.signatures.len()
+            )));
+        }
+        let results = tx.verify_with_results();
+        if let Some(slot) = results.iter().skip(1).position(|ok| !ok) {
+            return Err(reject(format!(
+                "signature of {} is missing or invalid",
+                keys[slot + 1]
+            )));
+        }
+        Ok(())
+    }
+
+    /// Validates `tx`, then adds the vault's fee payer signature without
+    /// broadcasting, like [`FireblocksSigner::sign_transaction_full`].
+    ///
+    /// Returns the completed transaction and the Fireblocks receipt.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::RelayRejected`] without contacting Fireblocks if `tx`
+    /// breaks a relay rule, otherwise fails like
+    /// [`FireblocksSigner::sign_in_place`].
+    pub fn relay(
+        &self,
+        mut tx: VersionedTransaction,
+    ) -> Result<(VersionedTransaction, SigningReceipt)> {
+        self.validate(&tx)?;
+        let sign_only = FireblocksSigner {
+            broadcast: false,
+            ..self.signer.clone()
+        };
+        let receipt = sign_only.sign_in_place(&mut tx)?;
+        Ok((tx, receipt))
+    }
+}
+
+fn reject(reason: String) -> Error {
+    Error::RelayRejected(reason)
+}
+
+/// The fee `tx` costs its fee payer: the signature fee plus the priority fee
+/// of its compute budget instructions.
+fn fee_lamports(tx: &VersionedTransaction) -> Result<u64> {
+    let message = &tx.message;
+    let keys = message.static_account_keys();
+    let is_budget = |ix: &CompiledInstruction| {
+        keys.get(usize::from(ix.program_id_index)) == Some(&COMPUTE_BUDGET_PROGRAM)
+    };
+    let (mut units, mut price) = (None, 0u64);
+    for ix in message.instructions().iter().filter(|ix| is_budget(ix)) {
+        match ix.data.split_first() {
+            Some((2, limit)) => {
+                let limit = limit
+                    .try_into()
+                    .map_err(|_| reject("malformed compute unit limit".to_string()))?;
+                units = Some(u64::from(u32::from_le_bytes(limit)));
+            }
+            Some((3, micro_lamports)) => {
+                let micro_lamports = micro_lamports
+                    .try_into()
+                    .map_err(|_| reject("malformed compute unit price".to_string()))?;
+                price = u64::from_le_bytes(micro_lamports);
+            }
+            _ => {}
+        }
+    }
+    let units = units
+        .unwrap_or_else(|| {
+            let other = message
+                .instructions()
+                .iter()
+                .filter(|ix| !is_budget(ix))
+                .count();
+            DEFAULT_UNITS_PER_INSTRUCTION.saturating_mul(other as u64)
+        })
+        .min(MAX_UNITS);
+    let priority = (u128::from(price) * u128::from(units)).div_ceil(MICRO_LAMPORTS_PER_LAMPORT);
+    let signatures = u64::from(message.header().num_required_signatures);
+    Ok(LAMPORTS_PER_SIGNATURE
+        .saturating_mul(signatures)
+        .saturating_add(u64::try_from(priority).unwrap_or(u64::MAX)))
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{VersionedTransactionExtension, test_util::remote_signer},
+        solana_sdk::{
+            hash::Hash,
+            instruction::{AccountMeta, Instruction},
+            signature::{Keypair, Signer},
+        },
+    };
+
+    fn memo(user: &Keypair, vault: Pubkey, mut accounts: Vec<AccountMeta>) -> VersionedTransaction {
+        accounts.push(AccountMeta::new_readonly(user.pubkey(), true));
+        let ix = Instruction {
+            program_id: spl_memo_interface::v3::id(),
+            accounts,
+            data: b"relay".to_vec(),
+        };
+        let mut tx = VersionedTransaction::new_unsigned_v0(&vault, &[ix], &[], Hash::default())
+            .expect("compiles");
+        tx.try_sign(&[user], None).expect("user signs");
+        tx
+    }
+
+    #[test]
+    fn test_relay_rules() -> anyhow::Result<()> {
+        let vault = Pubkey::new_unique();
+        let user = Keypair::new();
+        let relayer = Relayer::builder()
+            .signer(remote_signer(vault))
+            .allowed_programs(vec![spl_memo_interface::v3::id()])
+            .build();
+
+        relayer.validate(&memo(&user, vault, vec![]))?;
+
+        let drains_vault = memo(&user, vault, vec![AccountMeta::new(vault, true)]);
+        assert!(matches!(
+            relayer.validate(&drains_vault),
+            Err(Error::RelayRejected(_))
+        ));
+
+        let mut unsigned = memo(&user, vault, vec![]);
+        unsigned.signatures.pop();
+        assert!(matches!(
+            relayer.validate(&unsigned),
+            Err(Error::RelayRejected(_))
+        ));
+
+        let other_payer = memo(&user, user.pubkey(), vec![]);
+        assert!(relayer.validate(&other_payer).is_err());
+
+        let strict = Relayer::builder()
+            .signer(remote_signer(vault))
+            .allowed_programs(vec![])
+            .build();
+        assert!(strict.validate(&memo(&user, vault, vec![])).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_relay_fee_guard() -> anyhow::Result<()> {
+        let vault = Pubkey::new_unique();
+        let user = Keypair::new();
+        let priced = |micro_lamports: u64| {
+            let mut data = vec![3];
+            data.extend_from_slice(&micro_lamports.to_le_bytes());
+            let price = Instruction::new_with_bytes(COMPUTE_BUDGET_PROGRAM, &data, vec![]);
+            let memo = Instruction::new_with_bytes(spl_memo_interface::v3::id(), b"relay", vec![
+                AccountMeta::new_readonly(user.pubkey(), true),
+            ]);
+            let mut tx =
+                VersionedTransaction::new_unsigned_v0(&vault, &[price, memo], &[], Hash::default())
+                    .expect("compiles");
+            tx.try_sign(&[&user], None).expect("user signs");
+            tx
+        };
+        let relayer = Relayer::builder()
+            .signer(remote_signer(vault))
+            .allowed_programs(vec![spl_memo_interface::v3::id(), COMPUTE_BUDGET_PROGRAM])
+            .max_fee_lamports(20_000)
+            .build();
+
+        // 2 signatures and 200k units at 50k micro-lamports: 10_000 + 10_000.
+        assert_eq!(fee_lamports(&priced(50_000))?, 20_000);
+        relayer.validate(&priced(50_000))?;
+        assert!(matches!(
+            relayer.validate(&priced(50_001)),
+            Err(Error::RelayRejected(_))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+//! Optional error reporting of terminal signing failures to
+//! [Sentry](https://docs.rs/sentry), enabled with the `sentry` feature.
+//!
+//! Events are sent to the current Sentry hub, so the embedding application is
+//! responsible for initializing Sentry. Only sanitized context is attached:
+//! the Fireblocks transaction id, status and sub-status, plus the vault id.
+//! Transaction contents and credentials are never reported.
+//!
+//! Without the feature every function here is a no-op.
+
+use crate::TransactionStatus;
+
+/// Reports a transaction that ended in a failed status, e.g.
+/// `BLOCKED` by policy or `FAILED` with a `SIGNING_ERROR` sub-status.
+pub(crate) fn signing_failure(
+    vault_id: &str,
+    fireblocks_id: &str,
+    status: &TransactionStatus,
+    sub_status: &str,
+) {
+    #[cfg(feature = "sentry")]
+    sentry_core::with_scope(
+        |scope| {
+            scope.set_tag("fireblocks.vault", vault_id);
+            scope.set_tag("fireblocks.txid", fireblocks_id);
+            scope.set_tag("fireblocks.status", status);
+            scope.set_tag("fireblocks.sub_status", sub_status);
+        },
+        || {
+            sentry_core::capture_message(
+                &format!("fireblocks transaction {fireblocks_id} ended with {status} {sub_status}"),
+                sentry_core::Level::Error,
+            )
+        },
+    );
+    #[cfg(not(feature = "sentry"))]
+    let _ = (vault_id, fireblocks_id, status, sub_status);
+}
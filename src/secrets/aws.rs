@@ -0,0 +1,89 @@
+use {
+    super::{Credentials, SecretProvider},
+    crate::{Error, Result},
+    serde::Deserialize,
+    zeroize::Zeroizing,
+};
+
+/// Reads [`Credentials`] from an AWS Secrets Manager secret.
+///
+/// The secret string must be a JSON object with an `api_key` and a `secret`
+/// field. AWS credentials and the default region come from the usual AWS
+/// environment (variables, profile, instance role, ...).
+///
+/// # Examples
+///
+/// ```no_run
+/// use fireblocks_solana_signer::{AwsSecretsManager, FireblocksSigner, SOL, SignerOptions};
+///
+/// # fn main() -> anyhow::Result<()> {
+/// let provider = AwsSecretsManager::builder()
+///     .secret_id("prod/fireblocks/solana")
+///     .build();
+/// let signer = FireblocksSigner::try_from_provider(provider, "0", SOL, SignerOptions::default())?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, bon::Builder)]
+pub struct AwsSecretsManager {
+    /// The name or ARN of the secret.
+    #[builder(into)]
+    secret_id: String,
+
+    /// Overrides the AWS region.
+    #[builder(into)]
+    region: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct SecretJson {
+    api_key: String,
+    secret: String,
+}
+
+impl AwsSecretsManager {
+    async fn fetch(self) -> Result<String> {
+        let mut config = aws_config::defaults(aws_config::BehaviorVersion::latest());
+        if let Some(region) = self.region {
+            config = config.region(aws_config::Region::new(region));
+        }
+        let client = aws_sdk_secretsmanager::Client::new(&config.load().await);
+        let output = client
+            .get_secret_value()
+            .secret_id(&self.secret_id)
+            .send()
+            .await
+            .map_err(|e| Error::SecretProvider(format!("{}: {e}", self.secret_id)))?;
+        output.secret_string().map(str::to_string).ok_or_else(|| {
+            Error::SecretProvider(format!("{} has no secret string", self.secret_id))
+        })
+    }
+}
+
+impl SecretProvider for AwsSecretsManager {
+    fn credentials(&self) -> Result<Credentials> {
+        // The SDK is async; run it on a dedicated runtime so this works both
+        // inside and outside of an existing tokio runtime.
+        let provider = self.clone();
+        let json = std::thread::spawn(move || {
+            tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .map_err(|e| Error::SecretProvider(e.to_string()))?
+                .block_on(provider.fetch())
+        })
+        .join()
+        .map_err(|_| Error::ThreadPanic("AWS Secrets Manager fetch panicked".to_string()))??;
+        let json = Zeroizing::new(json);
+        let parsed: SecretJson = serde_json::from_str(&json).map_err(|e| {
+            Error::SecretProvider(format!(
+                "{}: expected api_key and secret: {e}",
+                self.secret_id
+            ))
+        })?;
+        Ok(Credentials {
+            api_key: parsed.api_key,
+            secret: Zeroizing::new(parsed.secret.into_bytes()),
+        })
+    }
+}
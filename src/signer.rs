@@ -29,30 +29,83 @@
 //! # }
 //! ```
 
+#[cfg(feature = "async")]
+mod asynchronous;
+mod cancel;
+#[cfg(feature = "config")]
 mod config;
+mod describe;
+mod ephemeral;
+mod health;
 mod keypair;
+#[cfg(feature = "keyring")]
+mod keyring;
+mod lifecycle;
+mod options;
 mod poll;
+mod progress;
+mod receipt;
+mod recent;
+#[cfg(feature = "tower")]
+mod service;
+mod signer_config;
+#[cfg(feature = "watchdog")]
+mod watchdog;
+mod workers;
+#[cfg(feature = "tower")]
+pub use service::*;
+#[cfg(feature = "watchdog")]
+pub use watchdog::*;
 use {
     crate::{
         Asset,
         Client,
         ClientBuilder,
+        ClientBuilderExt,
         EnvVar,
         Error,
+        ReadOnlyClient,
         Result,
+        TransactionResponse,
         TransactionStatus,
         VersionedTransactionExtension,
+        metrics::{self, Endpoint},
+        reporting,
+        secrets::Refresh,
+        status,
     },
     base64::prelude::*,
+    lifecycle::Lifecycle,
+    progress::Progress,
+    recent::Recent,
     solana_sdk::{
+        hash::Hash,
         message::VersionedMessage,
         pubkey::Pubkey,
         signature::{Keypair, Signature, Signer},
         transaction::VersionedTransaction,
     },
-    std::{fmt::Debug, str::FromStr, sync::Arc, time::Duration},
+    std::{
+        fmt::Debug,
+        str::FromStr,
+        sync::{Arc, mpsc::RecvTimeoutError},
+        time::Duration,
+    },
+    workers::Workers,
+};
+pub use {
+    cancel::*,
+    describe::*,
+    ephemeral::*,
+    health::*,
+    keypair::keypair_from_seed,
+    options::{SignOptions, SignerOptions},
+    poll::*,
+    progress::{PollEvent, PollEventHook, PollPhase},
+    receipt::*,
+    recent::DEFAULT_RECENT_CAPACITY,
+    signer_config::*,
 };
-pub use {keypair::keypair_from_seed, poll::*};
 
 /// A Solana signer implementation using Fireblocks as the backend signing
 /// service.
@@ -96,13 +149,95 @@ pub struct FireblocksSigner {
     /// Configuration for polling transaction status.
     pub poll_config: PollConfig,
 
+    /// A local keypair that signs instead of Fireblocks (compatibility mode,
+    /// see [`FireblocksSigner::new_with_keypair`]).
+    ///
+    /// The secret is never part of this signer's `Debug` output,
+    /// [`describe`](FireblocksSigner::describe) snapshot or
+    /// [`SignerConfig`]. It is shared by every clone rather than copied, and
+    /// when the last clone is dropped the underlying `ed25519-dalek` signing
+    /// key zeroizes its secret. Copies returned by
+    /// [`to_bytes`](FireblocksSigner::to_bytes) and
+    /// [`to_base58_string`](FireblocksSigner::to_base58_string) are wiped
+    /// when dropped.
     pub keypair: Option<Arc<Keypair>>,
 
     /// Sign and fireblocks will broadcast the transaction.
     pub broadcast: bool,
 
-    /// The Fireblocks client for API communication.
-    client: Option<Client>,
+    /// Value reported by [`Signer::is_interactive`].
+    ///
+    /// Defaults to `true` for Fireblocks-backed signers, since approval
+    /// policies may require a human. Set to `false` for vaults with automated
+    /// approval so tooling does not prompt unnecessarily.
+    #[builder(default = true)]
+    pub interactive: bool,
+
+    /// How many times a transaction that Fireblocks failed before signing,
+    /// with a transient sub-status (`CONNECTIVITY_ERROR` or `INTERNAL_ERROR`),
+    /// is created again. Defaults to `0`.
+    ///
+    /// Only transactions that reached the `FAILED` status are retried, so a
+    /// retry can never produce a second signature. API errors while creating
+    /// a transaction are not retried, since Fireblocks may have created it.
+    #[builder(default)]
+    pub create_retries: u8,
+
+    /// Opt-in circuit breaker that fails creates fast after repeated
+    /// failures, see [`BreakerConfig`]. Defaults to `None`, which never
+    /// blocks a create.
+    ///
+    /// The breaker state is shared by every clone of this signer.
+    pub breaker: Option<BreakerConfig>,
+
+    /// How long a [`Signer`] trait call waits for its signing thread before
+    /// failing with [`Error::Timeout`].
+    ///
+    /// Defaults to `None`, which waits for the poll timeout plus a margin for
+    /// the HTTP requests, once per
+    /// [`create_retries`](FireblocksSigner::create_retries) attempt. The
+    /// signing thread is not stopped when the wait gives up, so
+    /// a Fireblocks transaction may still complete afterwards.
+    pub sign_timeout: Option<Duration>,
+
+    /// Local signers that co-sign every transaction before it is sent to
+    /// Fireblocks. See [`FireblocksSigner::with_additional_signers`].
+    #[builder(default)]
+    pub additional_signers: Vec<Arc<dyn Signer + Send + Sync>>,
+
+    /// The Fireblocks API endpoint the client talks to, for diagnostics only.
+    #[builder(into)]
+    pub endpoint: Option<String>,
+
+    /// The Fireblocks client for API communication, shared by all clones so
+    /// cloning a signer never copies it.
+    #[builder(with = |client: Client| Arc::new(client))]
+    client: Option<Arc<Client>>,
+
+    /// Rebuilds the client from fresh credentials when Fireblocks rejects
+    /// them, shared by all clones. Set by
+    /// [`FireblocksSigner::try_from_provider`].
+    #[builder(skip)]
+    pub(crate) refresh: Option<Arc<Refresh>>,
+
+    /// In-flight tracking shared by all clones, see
+    /// [`FireblocksSigner::shutdown`].
+    #[builder(skip)]
+    lifecycle: Arc<Lifecycle>,
+
+    /// How many final Fireblocks responses [`FireblocksSigner::recent`], and
+    /// signatures [`FireblocksSigner::transaction_id_for`], remember.
+    /// Defaults to [`DEFAULT_RECENT_CAPACITY`]; `0` disables both.
+    #[builder(default = DEFAULT_RECENT_CAPACITY)]
+    pub recent_capacity: usize,
+
+    /// The latest final responses, shared by all clones.
+    #[builder(skip)]
+    recent: Arc<Recent>,
+
+    /// Threads running signs for the [`Signer`] trait, shared by all clones.
+    #[builder(skip)]
+    workers: Arc<Workers>,
 }
 
 impl PartialEq for FireblocksSigner {
@@ -130,25 +265,944 @@ impl Debug for FireblocksSigner {
 }
 
 impl FireblocksSigner {
+    /// Registers local signers that co-sign every transaction before the
+    /// Fireblocks round-trip.
+    ///
+    /// This is useful when a transaction requires signatures from both the
+    /// Fireblocks vault and locally held keys (e.g. a freshly generated stake
+    /// or mint account).
+    ///
+    /// # Ordering
+    ///
+    /// Additional signers sign in the order given, each placing its signature
+    /// in the slot matching its pubkey. Only once every local signature is in
+    /// place is the partially signed transaction submitted to Fireblocks, so
+    /// Fireblocks always sees the complete set of co-signatures.
+    ///
+    /// # Errors
+    ///
+    /// Signing fails before anything is sent to Fireblocks if an additional
+    /// signer is not a required signer of the message
+    /// ([`SignerError::KeypairPubkeyMismatch`]) or if the local signer itself
+    /// fails. Signers whose pubkey equals [`FireblocksSigner::pk`] are ignored.
+    ///
+    /// [`SignerError::KeypairPubkeyMismatch`]: solana_sdk::signature::SignerError::KeypairPubkeyMismatch
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use {fireblocks_solana_signer::FireblocksSigner, solana_sdk::signature::Keypair};
+    ///
+    /// # fn main() -> anyhow::Result<()> {
+    /// let stake_account = Keypair::new();
+    /// let signer = FireblocksSigner::try_from_env(None)?.with_additional_signers([stake_account]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_additional_signers<S>(mut self, signers: impl IntoIterator<Item = S>) -> Self
+    where
+        S: Signer + Send + Sync + 'static,
+    {
+        self.additional_signers.extend(
+            signers
+                .into_iter()
+                .map(|s| Arc::new(s) as Arc<dyn Signer + Send + Sync>),
+        );
+        self
+    }
+
+    /// Sets [`PollConfig::on_update`] on this signer's poll configuration.
+    ///
+    /// [`FireblocksSigner::try_from_env`] and `try_from_config` take a plain
+    /// `fn` callback. Use this to add a closure that captures state to a
+    /// signer they built.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use {
+    ///     fireblocks_solana_signer::{FireblocksSigner, TransactionResponse},
+    ///     std::sync::mpsc,
+    /// };
+    ///
+    /// # fn main() -> anyhow::Result<()> {
+    /// let (tx, rx) = mpsc::channel();
+    /// let signer =
+    ///     FireblocksSigner::try_from_env(None)?.with_on_update(move |r: &TransactionResponse| {
+    ///         let _ = tx.send(r.status.to_string());
+    ///     });
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_on_update(mut self, on_update: impl Into<PollHook>) -> Self {
+        self.poll_config.on_update = Some(on_update.into());
+        self
+    }
+
+    /// Partially signs `tx` with the configured
+    /// [`additional_signers`](FireblocksSigner::additional_signers).
+    fn partial_sign(&self, tx: &mut VersionedTransaction) -> Result<()> {
+        let message = tx.message.serialize();
+        for signer in &self.additional_signers {
+            let pubkey = signer.try_pubkey()?;
+            if pubkey == self.pk {
+                continue;
+            }
+            let position = tx
+                .get_signing_keypair_positions(&[pubkey])?
+                .first()
+                .copied()
+                .flatten()
+                .ok_or(solana_sdk::signature::SignerError::KeypairPubkeyMismatch)?;
+            tracing::debug!("local signer {pubkey} signing slot {position}");
+            tx.signatures[position] = signer.try_sign_message(&message)?;
+        }
+        Ok(())
+    }
+
     pub fn sign_versioned_transaction(&self, tx: &VersionedTransaction) -> Result<Signature> {
-        let client = self.client.as_ref().expect(
-            "FireblocksSigner must have either a keypair or a Fireblocks client configured",
+        self.sign_versioned_transaction_with_poll(tx, &self.poll_config)
+    }
+
+    /// Signs a versioned transaction using `poll` instead of the signer's
+    /// configured [`PollConfig`].
+    ///
+    /// Use this to override the polling interval, timeout, or callback for a
+    /// single sign, for example a verbose callback with a short interval in an
+    /// interactive CLI, while the signer keeps its defaults for every other
+    /// call.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use {
+    ///     fireblocks_solana_signer::{FireblocksSigner, PollConfig},
+    ///     solana_sdk::transaction::VersionedTransaction,
+    ///     std::time::Duration,
+    /// };
+    ///
+    /// # fn sign(signer: &FireblocksSigner, tx: &VersionedTransaction) -> anyhow::Result<()> {
+    /// let verbose = PollConfig::builder()
+    ///     .timeout(Duration::from_secs(120))
+    ///     .interval(Duration::from_secs(1))
+    ///     .callback(|t| println!("{t}"))
+    ///     .build();
+    /// let sig = signer.sign_versioned_transaction_with_poll(tx, &verbose)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn sign_versioned_transaction_with_poll(
+        &self,
+        tx: &VersionedTransaction,
+        poll: &PollConfig,
+    ) -> Result<Signature> {
+        self.sign_receipt(tx, poll).map(|r| r.signature)
+    }
+
+    /// Signs a versioned transaction with per-call overrides of the signer's
+    /// settings.
+    ///
+    /// One signer can serve both flows where Fireblocks broadcasts and flows
+    /// where the caller broadcasts, without building a second client.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use {
+    ///     fireblocks_solana_signer::{FireblocksSigner, SignOptions},
+    ///     solana_sdk::transaction::VersionedTransaction,
+    /// };
+    ///
+    /// # fn sign(signer: &FireblocksSigner, tx: &VersionedTransaction) -> anyhow::Result<()> {
+    /// // sign only, this transaction goes to a relayer
+    /// let options = SignOptions::builder().broadcast(false).build();
+    /// let sig = signer.sign_versioned_transaction_with(tx, &options)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn sign_versioned_transaction_with(
+        &self,
+        tx: &VersionedTransaction,
+        options: &SignOptions,
+    ) -> Result<Signature> {
+        let signer = Self {
+            broadcast: options.broadcast.unwrap_or(self.broadcast),
+            ..self.clone()
+        };
+        let poll = options.poll_config.as_ref().unwrap_or(&self.poll_config);
+        signer.sign_versioned_transaction_with_poll(tx, poll)
+    }
+
+    /// Signs a versioned transaction and returns a [`SigningReceipt`] with the
+    /// Fireblocks transaction id alongside the signature.
+    ///
+    /// The Fireblocks id is what the Fireblocks console and support work
+    /// with. The [`Signer`] trait methods stay unchanged and return the bare
+    /// signature.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use {
+    ///     fireblocks_solana_signer::FireblocksSigner,
+    ///     solana_sdk::transaction::VersionedTransaction,
+    /// };
+    ///
+    /// # fn sign(signer: &FireblocksSigner, tx: &VersionedTransaction) -> anyhow::Result<()> {
+    /// let receipt = signer.sign_with_receipt(tx)?;
+    /// println!("{} signed as {}", receipt.fireblocks_id, receipt.signature);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn sign_with_receipt(&self, tx: &VersionedTransaction) -> Result<SigningReceipt> {
+        self.sign_receipt(tx, &self.poll_config)
+    }
+
+    /// Signs a versioned transaction, giving up at `deadline`.
+    ///
+    /// The deadline caps the whole sign, unlike the signer's
+    /// [`PollConfig::timeout`], which only applies to polling. This lets
+    /// request-scoped services bound signing to their own SLA. Nothing is
+    /// created at Fireblocks once the deadline has passed. A transaction
+    /// already created is left as is; Fireblocks offers no way to cancel it
+    /// through this client.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Timeout`] if the deadline passed before the
+    /// transaction was created, or [`Error::FireblocksNoSig`] if it was still
+    /// pending at the deadline. Otherwise fails like
+    /// [`FireblocksSigner::sign_versioned_transaction`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use {
+    ///     fireblocks_solana_signer::FireblocksSigner,
+    ///     solana_sdk::transaction::VersionedTransaction,
+    ///     std::time::{Duration, Instant},
+    /// };
+    ///
+    /// # fn sign(signer: &FireblocksSigner, tx: &VersionedTransaction) -> anyhow::Result<()> {
+    /// let sig = signer.sign_with_deadline(tx, Instant::now() + Duration::from_secs(30))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn sign_with_deadline(
+        &self,
+        tx: &VersionedTransaction,
+        deadline: std::time::Instant,
+    ) -> Result<Signature> {
+        let poll = PollConfig {
+            deadline: Some(deadline),
+            ..self.poll_config.clone()
+        };
+        self.sign_versioned_transaction_with_poll(tx, &poll)
+    }
+
+    /// Co-signs `tx` with the additional signers, then signs it with
+    /// Fireblocks.
+    fn sign_receipt(&self, tx: &VersionedTransaction, poll: &PollConfig) -> Result<SigningReceipt> {
+        if self.additional_signers.is_empty() {
+            self.submit(tx, poll)
+        } else {
+            let mut tx = tx.clone();
+            self.partial_sign(&mut tx)?;
+            self.submit(&tx, poll)
+        }
+    }
+
+    /// Signs a versioned transaction and returns it with every signature in
+    /// place.
+    ///
+    /// The [`additional_signers`](FireblocksSigner::additional_signers) sign
+    /// first. The transaction is always created at Fireblocks as sign-only,
+    /// whatever [`broadcast`](FireblocksSigner::broadcast) is set to, since
+    /// the caller broadcasts the result (or hands it to a relayer). Once
+    /// polling returns the Fireblocks signature, it is verified against the
+    /// message and inserted at the slot belonging to
+    /// [`FireblocksSigner::pk`]. Signatures already present on `tx` are
+    /// preserved.
+    ///
+    /// # Errors
+    ///
+    /// Fails before contacting Fireblocks if [`FireblocksSigner::pk`] is not a
+    /// required signer of the message. Otherwise fails like
+    /// [`FireblocksSigner::sign_versioned_transaction`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use {
+    ///     fireblocks_solana_signer::FireblocksSigner,
+    ///     solana_sdk::transaction::VersionedTransaction,
+    /// };
+    ///
+    /// # fn sign(signer: &FireblocksSigner, tx: &VersionedTransaction) -> anyhow::Result<()> {
+    /// let signed = signer.sign_transaction_full(tx)?;
+    /// assert!(signed.verify_with_results().iter().all(|ok| *ok));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn sign_transaction_full(&self, tx: &VersionedTransaction) -> Result<VersionedTransaction> {
+        let sign_only = Self {
+            broadcast: false,
+            ..self.clone()
+        };
+        let mut tx = tx.clone();
+        sign_only.sign_in_place(&mut tx)?;
+        Ok(tx)
+    }
+
+    /// Signs `tx` in place, keeping every signature already present.
+    ///
+    /// Unlike [`FireblocksSigner::sign_transaction_full`], this follows
+    /// [`broadcast`](FireblocksSigner::broadcast).
+    ///
+    /// The additional signers sign first. The partially signed transaction is
+    /// then sent to Fireblocks, and the returned signature is checked against
+    /// the exact message that was sent before it is placed in the slot of
+    /// [`FireblocksSigner::pk`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::SignatureMismatch`] if the Fireblocks signature does
+    /// not verify against the message, in which case `tx` keeps only the
+    /// local signatures. Otherwise fails like
+    /// [`FireblocksSigner::sign_versioned_transaction`].
+    pub fn sign_in_place(&self, tx: &mut VersionedTransaction) -> Result<SigningReceipt> {
+        let position = self.signer_position(tx)?;
+        self.partial_sign(tx)?;
+        let receipt = self.submit(tx, &self.poll_config)?;
+        if !receipt
+            .signature
+            .verify(self.pk.as_ref(), &tx.message.serialize())
+        {
+            return Err(Error::SignatureMismatch(format!(
+                "txid {}: signature {} from Fireblocks does not match the submitted message",
+                receipt.fireblocks_id, receipt.signature
+            )));
+        }
+        tx.signatures[position] = receipt.signature;
+        Ok(receipt)
+    }
+
+    /// Returns the signature slot of [`FireblocksSigner::pk`] in `tx`.
+    fn signer_position(&self, tx: &VersionedTransaction) -> Result<usize> {
+        Ok(tx
+            .get_signing_keypair_positions(&[self.pk])?
+            .first()
+            .copied()
+            .flatten()
+            .ok_or(solana_sdk::signature::SignerError::KeypairPubkeyMismatch)?)
+    }
+
+    /// Signs each transaction in `txs` and returns one result per transaction,
+    /// in input order.
+    ///
+    /// Every transaction is submitted to Fireblocks before any of them is
+    /// polled, so approvals for the whole batch can proceed in parallel on the
+    /// Fireblocks side. A failure to sign one transaction does not affect the
+    /// others.
+    ///
+    /// Each transaction is otherwise signed like
+    /// [`FireblocksSigner::sign_with_receipt`]: it honours the poll deadline
+    /// and cancel token, is created again after transient failures (see
+    /// [`create_retries`](FireblocksSigner::create_retries)), and counts as
+    /// one sign in [`FireblocksSigner::stats`] and
+    /// [`FireblocksSigner::shutdown`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use {
+    ///     fireblocks_solana_signer::FireblocksSigner,
+    ///     solana_sdk::transaction::VersionedTransaction,
+    /// };
+    ///
+    /// # fn sign(signer: &FireblocksSigner, batch: Vec<VersionedTransaction>) {
+    /// for receipt in signer.sign_all(batch) {
+    ///     match receipt {
+    ///         Ok(r) => println!("{} signed as {}", r.fireblocks_id, r.signature),
+    ///         Err(e) => eprintln!("failed to sign: {e}"),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn sign_all(&self, txs: Vec<VersionedTransaction>) -> Vec<Result<SigningReceipt>> {
+        let poll = &self.poll_config;
+        // each sign holds its own in-flight guard until its receipt is known
+        let submitted: Vec<_> = txs
+            .into_iter()
+            .map(|mut tx| {
+                let span = self.sign_span(&tx);
+                let started = span.in_scope(|| {
+                    let in_flight = self.lifecycle.begin()?;
+                    let start = std::time::Instant::now();
+                    let progress = self
+                        .partial_sign(&mut tx)
+                        .and_then(|()| self.start_attempt(&tx, poll));
+                    Ok((in_flight, start, progress))
+                });
+                (span, tx, started)
+            })
+            .collect();
+        submitted
+            .into_iter()
+            .map(|(span, tx, started)| {
+                let _enter = span.enter();
+                let (_in_flight, start, progress) = started?;
+                let result =
+                    progress.and_then(|progress| self.finish_attempts(&tx, poll, progress));
+                self.lifecycle.record_sign(result.is_ok(), start.elapsed());
+                result
+            })
+            .collect()
+    }
+
+    /// Re-queries Fireblocks for the vault's address and checks it against
+    /// [`FireblocksSigner::pk`].
+    ///
+    /// The pubkey is resolved once at construction (or taken from
+    /// `FIREBLOCKS_PUBKEY`), so a vault reconfiguration or a stale override
+    /// goes unnoticed until Fireblocks signs with a different key. Call this
+    /// after such changes, or periodically, to detect the drift.
+    ///
+    /// Returns the freshly resolved pubkey.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::PubkeyChanged`] if the vault address no longer matches
+    /// [`FireblocksSigner::pk`], otherwise fails like
+    /// [`address_blocking_safe`](crate::address_blocking_safe), e.g. with
+    /// [`Error::Timeout`] if the lookup hangs.
+    pub fn refresh_pubkey(&self) -> Result<Pubkey> {
+        let pk = crate::address_blocking_safe(
+            self.client()?,
+            self.vault_id.clone(),
+            self.asset.clone(),
+        )?;
+        self.lifecycle.record_success();
+        if pk != self.pk {
+            return Err(Error::PubkeyChanged(format!(
+                "vault {} now resolves to {pk}, signer was configured with {}",
+                self.vault_id, self.pk
+            )));
+        }
+        Ok(pk)
+    }
+
+    /// Checks the signer's readiness, suitable for a service's `/healthz`
+    /// endpoint.
+    ///
+    /// Performs a vault address lookup to verify the API credentials are
+    /// accepted and that the vault still resolves to
+    /// [`FireblocksSigner::pk`] (see [`FireblocksSigner::refresh_pubkey`]).
+    /// The lookup is skipped while a Fireblocks API call succeeded within
+    /// the last 30 seconds and no create failed since, so frequent probes do
+    /// not add load on Fireblocks. Only an authentication failure clears
+    /// [`Health::credentials_valid`]; timeouts and network errors are
+    /// reported in [`Health::error`]. The remaining fields are read from
+    /// state shared by every clone of this signer.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fireblocks_solana_signer::FireblocksSigner;
+    ///
+    /// # fn main() -> anyhow::Result<()> {
+    /// let signer = FireblocksSigner::try_from_env(None)?;
+    /// let health = signer.health();
+    /// if !health.is_ready() {
+    ///     eprintln!("signer not ready: {health:?}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn health(&self) -> Health {
+        let fresh = self.lifecycle.failures() == 0
+            && self
+                .lifecycle
+                .last_success()
+                .and_then(|at| at.elapsed().ok())
+                .is_some_and(|age| age < health::CHECK_INTERVAL);
+        let check = if fresh {
+            Ok(())
+        } else {
+            self.refresh_pubkey().map(|_| ())
+        };
+        Health {
+            credentials_valid: !check.as_ref().is_err_and(Error::is_auth),
+            error: check.err().map(|e| e.to_string()),
+            last_success: self.lifecycle.last_success(),
+            in_flight: self.lifecycle.in_flight(),
+            shutting_down: self.lifecycle.is_closed(),
+            degraded: self.lifecycle.degraded(),
+            breaker: self.lifecycle.breaker(self.breaker),
+            consecutive_failures: self.lifecycle.failures(),
+        }
+    }
+
+    /// How many transactions this signer (and its clones) created at
+    /// Fireblocks, split by whether Fireblocks broadcast them.
+    ///
+    /// Useful to audit that the [`broadcast`](FireblocksSigner::broadcast)
+    /// setting matches expectations. With the `metrics` feature the same
+    /// counts are exported as `fireblocks_created_total`.
+    pub fn created_counts(&self) -> CreatedCounts {
+        let (broadcast, sign_only) = self.lifecycle.created_counts();
+        CreatedCounts {
+            broadcast,
+            sign_only,
+        }
+    }
+
+    /// Counts of signs attempted, succeeded and failed by this signer and its
+    /// clones, with the latest sign duration and the number in flight.
+    ///
+    /// Maintained with atomics whether or not the `metrics` feature is
+    /// enabled, so applications can report signer health without a metrics
+    /// recorder.
+    pub fn stats(&self) -> SignerStats {
+        self.lifecycle.stats()
+    }
+
+    /// The final Fireblocks responses of the latest signs by this signer and
+    /// its clones, most recently used first.
+    ///
+    /// Up to [`recent_capacity`](FireblocksSigner::recent_capacity) responses
+    /// are kept, failed ones included, so API handlers can answer "what
+    /// happened to my transfer?" without another Fireblocks round-trip.
+    pub fn recent(&self) -> Vec<TransactionResponse> {
+        self.recent.all()
+    }
+
+    /// The remembered final response of the Fireblocks transaction `id`, see
+    /// [`FireblocksSigner::recent`].
+    pub fn recent_by_id(&self, id: &str) -> Option<TransactionResponse> {
+        self.recent.find(|_, r| r.id == id)
+    }
+
+    /// The remembered final response of the latest sign of the message
+    /// hashed to `message_hash` (see [`VersionedMessage::hash`]), see
+    /// [`FireblocksSigner::recent`].
+    pub fn recent_by_message(&self, message_hash: &Hash) -> Option<TransactionResponse> {
+        self.recent.find(|h, _| h == message_hash)
+    }
+
+    /// The Fireblocks id of the latest signature produced by this signer or
+    /// its clones.
+    ///
+    /// Useful for audit logging in code that only sees the [`Signer`] trait,
+    /// which returns bare signatures.
+    pub fn last_transaction_id(&self) -> Option<String> {
+        self.recent.last_id()
+    }
+
+    /// The Fireblocks id of the transaction that produced `signature`.
+    ///
+    /// The latest [`recent_capacity`](FireblocksSigner::recent_capacity)
+    /// signatures are remembered.
+    pub fn transaction_id_for(&self, signature: &Signature) -> Option<String> {
+        self.recent.id_for(signature)
+    }
+
+    /// Stops accepting new signs and waits up to `timeout` for in-flight
+    /// Fireblocks transactions to reach a final status.
+    ///
+    /// Shutdown applies to this signer and every clone of it. Once called,
+    /// any new signing request fails with [`Error::ShuttingDown`], while signs
+    /// already submitted to Fireblocks keep polling until they complete. Call
+    /// this before a service redeploy so approvals are not abandoned
+    /// mid-flight.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Timeout`] if signs are still in flight after
+    /// `timeout`. Those signs keep running in the background.
+    pub fn shutdown(&self, timeout: Duration) -> Result<()> {
+        tracing::info!(
+            "{self:?} shutting down, {} sign(s) in flight",
+            self.lifecycle.in_flight()
         );
+        self.lifecycle.close(timeout)
+    }
+
+    /// Fetches the current state of the Fireblocks transaction `id` without
+    /// waiting for it to reach a final status.
+    pub(crate) fn fetch_status(&self, id: &str) -> Result<TransactionResponse> {
+        let response = self.read_only()?.transaction(id)?;
+        self.lifecycle.record_success();
+        Ok(response)
+    }
+
+    /// A [`ReadOnlyClient`] sharing this signer's Fireblocks client.
+    ///
+    /// Hand it to monitoring code that needs to query transactions or vault
+    /// addresses but must never create transactions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Misconfigured`] if this signer has no Fireblocks
+    /// client, i.e. it signs with a local keypair only.
+    pub fn read_only(&self) -> Result<ReadOnlyClient> {
+        self.client()
+            .map(|client| ReadOnlyClient::from(Client::clone(&client)))
+            .map_err(|_| Error::Misconfigured(format!("{self:?} has no Fireblocks client")))
+    }
+
+    /// Checks that this signer can sign: it needs a local keypair or a
+    /// Fireblocks client, and a keypair must match [`FireblocksSigner::pk`].
+    ///
+    /// Signing performs the same checks, so calling this is only needed to
+    /// catch a misconfigured signer at startup rather than on first use.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Misconfigured`] describing the problem.
+    pub fn validate(&self) -> Result<()> {
+        match &self.keypair {
+            Some(keypair) if keypair.pubkey() != self.pk => Err(Error::Misconfigured(format!(
+                "{self:?} keypair {} does not match pk {}",
+                keypair.pubkey(),
+                self.pk
+            ))),
+            Some(_) => Ok(()),
+            None => self.client().map(|_| ()),
+        }
+    }
+
+    /// The client rebuilt by the latest credentials refresh, otherwise the one
+    /// this signer was built with.
+    fn client(&self) -> Result<Arc<Client>> {
+        if let Some(client) = self.refresh.as_ref().and_then(|r| r.client()) {
+            return Ok(client);
+        }
+        self.client.clone().ok_or_else(|| {
+            Error::Misconfigured(format!(
+                "{self:?} has neither a keypair nor a Fireblocks client"
+            ))
+        })
+    }
+
+    /// Submits an already partially signed transaction to Fireblocks and polls
+    /// for the resulting signature.
+    fn submit(&self, tx: &VersionedTransaction, poll: &PollConfig) -> Result<SigningReceipt> {
+        let _in_flight = self.lifecycle.begin()?;
+        let span = self.sign_span(tx);
+        let _enter = span.enter();
+        let start = std::time::Instant::now();
+        let result = self.create_and_await(tx, poll);
+        self.lifecycle.record_sign(result.is_ok(), start.elapsed());
+        result
+    }
+
+    /// Creates the Fireblocks transaction for `tx` and waits for its receipt,
+    /// retrying transient failures (see
+    /// [`create_retries`](FireblocksSigner::create_retries)).
+    fn create_and_await(
+        &self,
+        tx: &VersionedTransaction,
+        poll: &PollConfig,
+    ) -> Result<SigningReceipt> {
+        let progress = self.start_attempt(tx, poll)?;
+        self.finish_attempts(tx, poll, progress)
+    }
+
+    /// Creates the Fireblocks transaction for `tx`, unless the deadline of
+    /// `poll` has passed or it was cancelled.
+    fn start_attempt(&self, tx: &VersionedTransaction, poll: &PollConfig) -> Result<Progress> {
+        poll.check_deadline()?;
+        poll.check_cancelled()?;
+        Ok(Progress::submitted(self.create(tx)?, poll))
+    }
+
+    /// Waits for the receipt of the transaction `progress` tracks, creating
+    /// `tx` again after transient failures.
+    fn finish_attempts(
+        &self,
+        tx: &VersionedTransaction,
+        poll: &PollConfig,
+        mut progress: Progress,
+    ) -> Result<SigningReceipt> {
+        let program = metrics::program(tx);
+        let message_hash = tx.message.hash();
+        let mut attempts = 1;
+        loop {
+            let polled = self.await_final(&progress, poll)?;
+            self.recent
+                .record(message_hash, &polled.0, self.recent_capacity);
+            if attempts <= u32::from(self.create_retries) && status::is_transient_failure(&polled.0)
+            {
+                tracing::warn!(
+                    "txid {} failed with a transient sub-status, retry {attempts} of {}",
+                    progress.id(),
+                    self.create_retries
+                );
+                attempts += 1;
+                progress = self.start_attempt(tx, poll)?;
+                continue;
+            }
+            let mut receipt = self.receipt(polled, &program, poll)?;
+            receipt.attempts = attempts;
+            return Ok(receipt);
+        }
+    }
+
+    /// The span covering the whole life of one sign.
+    ///
+    /// `fireblocks_id`, `status` and `signature` are recorded as they become
+    /// known. Creating the transaction and polling it get child spans,
+    /// `fireblocks_create` and `fireblocks_poll`, once per attempt. Poll
+    /// callbacks run inside the poll span, so every status update they log is
+    /// attributed to the same transaction. With `tracing-opentelemetry`
+    /// installed, the spans join the caller's distributed trace.
+    fn sign_span(&self, tx: &VersionedTransaction) -> tracing::Span {
+        tracing::info_span!(
+            "fireblocks_sign",
+            vault = %self.vault_id,
+            asset = %self.asset,
+            message_hash = %tx.message.hash(),
+            fireblocks_id = tracing::field::Empty,
+            status = tracing::field::Empty,
+            signature = tracing::field::Empty,
+        )
+    }
 
+    /// Creates the Fireblocks transaction for `tx`, returning its id.
+    ///
+    /// API errors that prove no transaction was created are returned as
+    /// [`Error::NotCreated`], any other as [`Error::CreateFailed`], since the
+    /// transaction may exist after those. Both count towards the opt-in
+    /// circuit breaker (see [`FireblocksSigner::breaker`]). While it is open
+    /// this fails with [`Error::CircuitOpen`] without calling Fireblocks.
+    ///
+    /// If Fireblocks rejects the credentials of a signer built with
+    /// [`FireblocksSigner::try_from_provider`], they are fetched again and
+    /// the create is retried once with a rebuilt client.
+    fn create(&self, tx: &VersionedTransaction) -> Result<String> {
+        let client = self.client()?;
         let transaction_base64 = BASE64_STANDARD.encode(bincode::serialize(tx)?);
+        self.lifecycle.check_breaker(self.breaker)?;
 
         tracing::debug!("tx base64 {transaction_base64}");
-        let resp = if self.broadcast {
-            client.program_call(&self.asset, &self.vault_id, transaction_base64)?
-        } else {
-            client.sign_only(&self.asset, &self.vault_id, transaction_base64)?
+        let send = |client: &Client| {
+            metrics::timed(Endpoint::Create, || {
+                if self.broadcast {
+                    client.program_call(&self.asset, &self.vault_id, transaction_base64.clone())
+                } else {
+                    client.sign_only(&self.asset, &self.vault_id, transaction_base64.clone())
+                }
+            })
         };
-        let (result, sig) = client.poll(
-            &resp.id,
-            self.poll_config.timeout,
-            self.poll_config.interval,
-            self.poll_config.callback,
-        )?;
+        let span = tracing::info_span!("fireblocks_create", broadcast = self.broadcast);
+        let resp = span
+            .in_scope(|| match (send(&client), &self.refresh) {
+                (Err(e), Some(refresh)) if crate::error::rejected_credentials(&e) => {
+                    tracing::warn!("{self:?} credentials rejected, fetching them again: {e}");
+                    match refresh.refresh() {
+                        Ok(client) => send(&client),
+                        Err(refresh_error) => {
+                            tracing::warn!("{self:?} credentials refresh failed: {refresh_error}");
+                            Err(e)
+                        }
+                    }
+                }
+                (resp, _) => resp,
+            })
+            .map_err(|e| {
+                self.lifecycle.record_failure(self.breaker);
+                if crate::error::never_created(&e) {
+                    Error::NotCreated(e)
+                } else {
+                    Error::CreateFailed(e)
+                }
+            })?;
+        self.lifecycle.record_success();
+        self.lifecycle.record_created(self.broadcast);
+        metrics::created(self.broadcast);
+        tracing::Span::current().record("fireblocks_id", resp.id.as_str());
+        tracing::debug!("created fireblocks transaction {}", resp.id);
+        Ok(resp.id)
+    }
+
+    /// Polls the transaction of `progress` until it reaches a final status or
+    /// `poll.timeout` elapses, reporting it as slow once `poll.warn_after`
+    /// has passed.
+    fn poll_until_final(
+        &self,
+        progress: &Progress,
+        poll: &PollConfig,
+    ) -> Result<(TransactionResponse, Option<String>)> {
+        let id = progress.id();
+        let timeout = poll.remaining();
+        let Some(warn_after) = poll.warn_after.filter(|w| *w < timeout) else {
+            return self.poll_for(progress, timeout, poll);
+        };
+        let (result, sig) = self.poll_for(progress, warn_after, poll)?;
+        if !status::is_pending(&result.status) {
+            return Ok((result, sig));
+        }
+        tracing::warn!(
+            "txid {id} still {} after {warn_after:?}, waiting on approval?",
+            result.status
+        );
+        if let Some(on_slow) = &poll.on_slow {
+            on_slow.call(&result);
+        }
+        self.poll_for(progress, timeout - warn_after, poll)
+    }
+
+    /// Polls the transaction of `progress` for up to `timeout`.
+    ///
+    /// With a [`CancelToken`], an [`on_update`](PollConfig::on_update) or an
+    /// [`on_status_change`](PollConfig::on_status_change) hook, or a
+    /// [`PollStrategy`] other than `Fixed`, or with the `metrics` feature, the
+    /// wait is split into single status requests one poll interval apart.
+    /// Between them the signer checks for cancellation, passes the latest
+    /// response to the hooks and picks the next interval.
+    fn poll_for(
+        &self,
+        progress: &Progress,
+        timeout: Duration,
+        poll: &PollConfig,
+    ) -> Result<(TransactionResponse, Option<String>)> {
+        let client = self.client()?;
+        let id = progress.id();
+        #[cfg(feature = "webhook")]
+        if let Some(webhook) = &poll.webhook {
+            return self.await_webhook(progress, timeout, poll, webhook);
+        }
+        if !poll.is_sliced() {
+            let (result, sig) = client.poll(id, timeout, poll.interval, poll.callback)?;
+            progress.observe(poll, &result);
+            return Ok((result, sig));
+        }
+        let deadline = std::time::Instant::now() + timeout;
+        let mut interval = poll.strategy.first(poll.interval);
+        loop {
+            poll.check_cancelled()?;
+            let (result, sig) = self.get(id, poll)?;
+            progress.observe(poll, &result);
+            let left = deadline.saturating_duration_since(std::time::Instant::now());
+            if left.is_zero() || !status::is_pending(&result.status) {
+                return Ok((result, sig));
+            }
+            poll.sleep(interval.min(left))?;
+            interval = poll.strategy.next(interval, &result.status, poll.interval);
+        }
+    }
+
+    /// Fetches the current state of the Fireblocks transaction `id` with a
+    /// single request, passing it to `poll.callback`.
+    fn get(&self, id: &str, poll: &PollConfig) -> Result<(TransactionResponse, Option<String>)> {
+        let client = self.client()?;
+        Ok(metrics::timed(Endpoint::Get, || {
+            client.poll(id, Duration::ZERO, Duration::ZERO, poll.callback)
+        })?)
+    }
+
+    /// Fetches the transaction of `progress` each time `webhook` reports a
+    /// status change, for up to `timeout`, falling back to polling every
+    /// [`WebhookListener::fallback`](crate::WebhookListener::fallback).
+    #[cfg(feature = "webhook")]
+    fn await_webhook(
+        &self,
+        progress: &Progress,
+        timeout: Duration,
+        poll: &PollConfig,
+        webhook: &crate::WebhookListener,
+    ) -> Result<(TransactionResponse, Option<String>)> {
+        let id = progress.id();
+        let watch = webhook.watch(id);
+        let deadline = std::time::Instant::now() + timeout;
+        loop {
+            poll.check_cancelled()?;
+            let (result, sig) = self.get(id, poll)?;
+            progress.observe(poll, &result);
+            let left = deadline.saturating_duration_since(std::time::Instant::now());
+            if left.is_zero() || !status::is_pending(&result.status) {
+                return Ok((result, sig));
+            }
+            let mut wait = left.min(webhook.fallback());
+            if poll.cancel.is_some() {
+                wait = wait.min(poll.interval);
+            }
+            watch.wait(wait);
+        }
+    }
+
+    /// Keeps polling a transaction that `poll_until_final` left in
+    /// `BROADCASTING`, reporting it once `poll.broadcasting_after` has passed.
+    fn await_broadcast(
+        &self,
+        progress: &Progress,
+        poll: &PollConfig,
+        (mut result, mut sig): (TransactionResponse, Option<String>),
+    ) -> Result<(TransactionResponse, Option<String>)> {
+        let Some(after) = poll.broadcasting_after.map(|a| a.min(poll.remaining())) else {
+            return Ok((result, sig));
+        };
+        let id = progress.id();
+        let deadline = std::time::Instant::now() + after;
+        while matches!(result.status, TransactionStatus::Broadcasting) {
+            let now = std::time::Instant::now();
+            if now >= deadline {
+                tracing::warn!(
+                    "txid {id} still {} after {after:?}, Fireblocks may be failing to reach the \
+                     network",
+                    result.status
+                );
+                if let Some(on_broadcasting) = &poll.on_broadcasting {
+                    on_broadcasting.call(&result);
+                }
+                break;
+            }
+            let interval = poll
+                .strategy
+                .next(poll.interval, &result.status, poll.interval);
+            poll.sleep(interval.min(deadline - now))?;
+            (result, sig) = self.get(id, poll)?;
+            progress.observe(poll, &result);
+        }
+        Ok((result, sig))
+    }
+
+    /// Polls the Fireblocks transaction of `progress` until it reaches a
+    /// final status, recording that status.
+    fn await_final(
+        &self,
+        progress: &Progress,
+        poll: &PollConfig,
+    ) -> Result<(TransactionResponse, Option<String>)> {
+        let span = tracing::info_span!(
+            "fireblocks_poll",
+            fireblocks_id = progress.id(),
+            status = tracing::field::Empty
+        );
+        let start = std::time::Instant::now();
+        let (result, sig) = span.in_scope(|| {
+            self.poll_until_final(progress, poll)
+                .and_then(|polled| self.await_broadcast(progress, poll, polled))
+        })?;
+        metrics::approval_wait(start.elapsed(), &result.status);
+        progress.finish(poll, &result);
+        span.record("status", tracing::field::display(&result.status));
+        self.lifecycle.record_success();
+        metrics::final_status(&result.status);
+        tracing::Span::current().record("status", tracing::field::display(&result.status));
+        Ok((result, sig))
+    }
+
+    /// Turns a polled final status into a receipt, or the matching error.
+    ///
+    /// `program` labels policy rejections, see [`metrics::policy_rejection`].
+    fn receipt(
+        &self,
+        (result, sig): (TransactionResponse, Option<String>),
+        program: &str,
+        poll: &PollConfig,
+    ) -> Result<SigningReceipt> {
         match &result.status {
             // These statuses indicate the transaction is still pending and shouldn't have been
             // returned by polling
@@ -175,11 +1229,22 @@ impl FireblocksSigner {
             | TransactionStatus::Rejected
             | TransactionStatus::Cancelled
             | TransactionStatus::Cancelling => {
+                let sub_status = result.sub_status.unwrap_or_default().to_string();
+                if matches!(
+                    result.status,
+                    TransactionStatus::Blocked | TransactionStatus::Rejected
+                ) {
+                    if let Some(on_blocked) = &poll.on_blocked {
+                        on_blocked.call(&result);
+                    }
+                    metrics::policy_rejection(&result.status, &sub_status, program);
+                }
+                reporting::signing_failure(&self.vault_id, &result.id, &result.status, &sub_status);
                 return Err(crate::Error::FireblocksNoSig(format!(
                     "txid: {} failed with status {} substatus: \"{}\" error: {}",
                     result.id,
                     result.status,
-                    result.sub_status.unwrap_or_default(),
+                    sub_status,
                     result
                         .error_description
                         .as_ref()
@@ -217,7 +1282,21 @@ impl FireblocksSigner {
                     .as_ref()
                     .map_or("unknown error", |v| v)
             ))),
-            Some(s) => Ok(Signature::from_str(&s)?),
+            Some(s) => {
+                let signature = Signature::from_str(&s)?;
+                tracing::Span::current().record("signature", tracing::field::display(&signature));
+                self.recent
+                    .record_signature(signature, &result.id, self.recent_capacity);
+                Ok(SigningReceipt {
+                    signature,
+                    fireblocks_id: result.id.clone(),
+                    status: result.status.clone(),
+                    sub_status: result.sub_status.as_ref().map(ToString::to_string),
+                    tx_hash: result.tx_hash.clone().filter(|hash| !hash.is_empty()),
+                    response: result,
+                    attempts: 1,
+                })
+            }
         }
     }
 
@@ -246,11 +1325,7 @@ impl FireblocksSigner {
     /// - The Fireblocks API call fails
     /// - Polling times out
     /// - No signature is returned from Fireblocks
-    ///
-    /// # Panics
-    ///
-    /// Panics if neither a keypair nor a Fireblocks client is configured.
-    /// This indicates a fundamental configuration error in the signer setup.
+    /// - No Fireblocks client is configured ([`Error::Misconfigured`])
     #[tracing::instrument(level = "debug", skip(message))]
     fn sign_transaction(&self, message: &[u8]) -> Result<Signature> {
         let versioned_message: VersionedMessage = bincode::deserialize(message)
@@ -259,6 +1334,115 @@ impl FireblocksSigner {
         self.sign_versioned_transaction(&versioned_transaction)
     }
 
+    /// Runs [`FireblocksSigner::sign_transaction`] on a background worker
+    /// thread and waits for the result.
+    ///
+    /// The blocking Fireblocks client must never run on a tokio worker, so
+    /// every [`Signer`] entry point funnels through here.
+    pub(crate) fn sign_message_detached(&self, message: &[u8]) -> Result<Signature> {
+        let message_vec = message.to_vec();
+        let signer = self.clone();
+
+        tracing::debug!("running sign_transaction on a worker thread");
+
+        // Plain OS threads work in every context, tokio or not
+        let (tx, rx) = std::sync::mpsc::channel();
+
+        self.workers.execute(move || {
+            let _ = tx.send(signer.sign_transaction(&message_vec));
+        });
+
+        let wait = self.sign_wait();
+        tracing::debug!("waiting up to {wait:?} for response...");
+        // Wait for the result synchronously (could take 2+ minutes)
+        match rx.recv_timeout(wait) {
+            Ok(result) => result,
+            Err(RecvTimeoutError::Timeout) => Err(Error::Timeout(format!(
+                "no signature from the signing thread after {wait:?}"
+            ))),
+            Err(RecvTimeoutError::Disconnected) => {
+                Err(Error::ChannelClosed("Channel closed".to_string()))
+            }
+        }
+    }
+
+    /// How long [`FireblocksSigner::sign_message_detached`] waits, see
+    /// [`FireblocksSigner::sign_timeout`].
+    fn sign_wait(&self) -> Duration {
+        self.sign_timeout.unwrap_or_else(|| {
+            // the create request and the last poll request may each take up
+            // to the client timeout
+            let margin = Duration::from_secs(2 * u64::from(crate::DEFAULT_CLIENT_TIMEOUT));
+            let attempt = self.poll_config.timeout
+                + self.poll_config.broadcasting_after.unwrap_or_default()
+                + margin;
+            attempt * (u32::from(self.create_retries) + 1)
+        })
+    }
+
+    /// Creates a new [`FireblocksSigner`] from explicit credentials, without
+    /// reading any environment variable.
+    ///
+    /// Use this when the credentials come from a secret store, or when one
+    /// process serves several tenants.
+    ///
+    /// * `api_key` - UUID of the Fireblocks API user
+    /// * `secret_pem` - RSA private key of the API user, in any form accepted
+    ///   by [`secret_pem`](crate::secret_pem)
+    /// * `vault_id` - the Fireblocks vault id
+    /// * `asset` - the Fireblocks asset id ([`SOL`](crate::SOL) or
+    ///   [`SOL_TEST`](crate::SOL_TEST))
+    /// * `options` - everything else, see [`SignerOptions`]
+    ///
+    /// # Errors
+    ///
+    /// Fails if the secret is not an RSA key, the client cannot be built, or
+    /// the pubkey cannot be looked up.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fireblocks_solana_signer::{FireblocksSigner, SOL_TEST, SignerOptions};
+    ///
+    /// # fn main() -> anyhow::Result<()> {
+    /// # let (api_key, secret) = (String::new(), Vec::<u8>::new());
+    /// let signer =
+    ///     FireblocksSigner::try_new(&api_key, &secret, "0", SOL_TEST, SignerOptions::default())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_new(
+        api_key: &str,
+        secret_pem: &[u8],
+        vault_id: impl Into<String>,
+        asset: Asset,
+        options: SignerOptions,
+    ) -> Result<Self> {
+        let vault_id = vault_id.into();
+        let secret = crate::secret_pem(secret_pem)?;
+        let builder = ClientBuilder::new(api_key, &secret)
+            .with_url(&options.endpoint)
+            .with_timeout(options.client_timeout);
+        let (client, pk) = crate::build_client_and_address_blocking_safe(
+            builder,
+            vault_id.clone(),
+            asset.clone(),
+            options.pubkey.map(|pk| pk.to_string()),
+        )?;
+        Ok(FireblocksSigner::builder()
+            .client(client)
+            .vault_id(vault_id)
+            .asset(asset)
+            .pk(pk)
+            .endpoint(options.endpoint)
+            .poll_config(options.poll_config)
+            .broadcast(options.broadcast)
+            .interactive(options.interactive)
+            .create_retries(options.create_retries)
+            .maybe_breaker(options.breaker)
+            .build())
+    }
+
     /// Creates a new [`FireblocksSigner`] from environment variables.
     ///
     /// This is the primary way to instantiate a signer, reading configuration
@@ -274,8 +1458,17 @@ impl FireblocksSigner {
     /// # Optional Environment Variables
     ///
     /// - `FIREBLOCKS_TESTNET` or `FIREBLOCKS_DEVNET`: Set to use testnet asset
+    /// - `FIREBLOCKS_SECRET_PATH`: File holding the RSA private key, used
+    ///   instead of `FIREBLOCKS_SECRET`
     /// - `FIREBLOCKS_POLL_TIMEOUT`: Polling timeout in seconds (default: 60)
     /// - `FIREBLOCKS_POLL_INTERVAL`: Polling interval in seconds (default: 5)
+    /// - `FIREBLOCKS_CLIENT_TIMEOUT`: HTTP timeout in seconds (default: 15)
+    /// - `FIREBLOCKS_BROADCAST`: Set to `true` to have Fireblocks broadcast
+    ///   signed transactions (default: false)
+    /// - `FIREBLOCKS_INTERACTIVE`: Set to `false` to report the signer as
+    ///   non-interactive, e.g. for automated approval vaults (default: true)
+    /// - `FIREBLOCKS_POLL_LOG`: Verbosity of the default poll callback,
+    ///   `silent`, `debug`, `info` or `warn` (default: info), see [`PollLog`]
     ///
     /// # Arguments
     ///
@@ -311,31 +1504,15 @@ impl FireblocksSigner {
     /// # Ok(())
     /// # }
     /// ```
-    /// Creates a new `FireblocksSigner` from environment variables.
-    ///
-    /// # Environment Variables
-    ///
-    /// - `FIREBLOCKS_VAULT`: The vault ID
-    /// - `FIREBLOCKS_TESTNET` or `FIREBLOCKS_DEVNET`: Set to any value if using
-    ///   testnet/devnet
-    /// - `FIREBLOCKS_SECRET`: The RSA private key
-    /// - `FIREBLOCKS_API_KEY`: The API key
-    /// - `FIREBLOCKS_ENDPOINT`: The API endpoint
-    /// - `FIREBLOCKS_POLL_TIMEOUT`: Timeout in seconds (default: 60)
-    /// - `FIREBLOCKS_POLL_INTERVAL`: Polling interval in seconds (default: 5)
-    ///
-    /// # Examples
-    ///
-    /// ```no_run
-    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// use fireblocks_solana_signer::FireblocksSigner;
-    ///
-    /// // Create from environment variables
-    /// let signer = FireblocksSigner::try_from_env(None)?;
-    /// # Ok(())
-    /// # }
-    /// ```
     pub fn try_from_env(f: Option<fn(&crate::TransactionResponse)>) -> Result<Self> {
+        Self::from_env_with_client(ClientBuilder::from_env()?, f)
+    }
+
+    /// [`FireblocksSigner::try_from_env`] with the client already configured.
+    fn from_env_with_client(
+        builder: ClientBuilder,
+        f: Option<fn(&crate::TransactionResponse)>,
+    ) -> Result<Self> {
         let vault = std::env::var(EnvVar::Vault).map_err(|e| Error::from((EnvVar::Vault, e)))?;
         let asset =
             if std::env::var(EnvVar::Testnet).is_ok() || std::env::var(EnvVar::Devnet).is_ok() {
@@ -343,15 +1520,9 @@ impl FireblocksSigner {
             } else {
                 crate::SOL
             };
-        let key = std::env::var(EnvVar::Secret).map_err(|e| Error::from((EnvVar::Secret, e)))?;
-        let api = std::env::var(EnvVar::ApiKey).map_err(|e| Error::from((EnvVar::ApiKey, e)))?;
         let address: Option<String> = std::env::var(EnvVar::Pubkey).ok();
         let endpoint =
             std::env::var(EnvVar::Endpoint).map_err(|e| Error::from((EnvVar::Endpoint, e)))?;
-        let rsa_pem = key.as_bytes().to_vec();
-        let builder = ClientBuilder::new(&api, &rsa_pem)
-            .with_url(&endpoint)
-            .with_timeout(Duration::from_secs(crate::DEFAULT_CLIENT_TIMEOUT.into()));
         let (client, pk) = crate::build_client_and_address_blocking_safe(
             builder,
             vault.clone(),
@@ -372,7 +1543,19 @@ impl FireblocksSigner {
                 .unwrap_or(5),
         );
 
-        let cb = f.unwrap_or(default_poll.callback);
+        let interactive = std::env::var(EnvVar::Interactive).map_or(true, |v| {
+            !matches!(v.to_lowercase().as_str(), "false" | "0" | "no")
+        });
+        let broadcast = std::env::var(EnvVar::Broadcast)
+            .is_ok_and(|v| matches!(v.to_lowercase().as_str(), "true" | "1" | "yes"));
+
+        let cb = match f {
+            Some(f) => f,
+            None => match std::env::var(EnvVar::PollLog) {
+                Ok(level) => level.parse::<PollLog>()?.callback(),
+                Err(_) => default_poll.callback,
+            },
+        };
         let poll = PollConfig::builder()
             .timeout(poll_timeout)
             .interval(poll_interval)
@@ -384,7 +1567,9 @@ impl FireblocksSigner {
             .asset(asset)
             .poll_config(poll)
             .pk(pk)
-            .broadcast(false)
+            .endpoint(endpoint)
+            .broadcast(broadcast)
+            .interactive(interactive)
             .build())
     }
 }
@@ -428,50 +1613,120 @@ impl Signer for FireblocksSigner {
     ) -> std::result::Result<Signature, solana_sdk::signature::SignerError> {
         match &self.keypair {
             Some(kp) => kp.try_sign_message(message),
-            None => {
-                let message_vec = message.to_vec();
-                let signer = self.clone();
-
-                tracing::debug!("spawning sign_transaction call with std::thread::spawn");
-
-                // Use std::thread::spawn for universal compatibility across all contexts
-                let (tx, rx) = std::sync::mpsc::channel();
-
-                std::thread::spawn(move || {
-                    let result = signer.sign_transaction(&message_vec);
-                    let final_result =
-                        result.map_err(|e| solana_sdk::signer::SignerError::Custom(format!("{e}")));
-                    let _ = tx.send(final_result);
-                });
-
-                tracing::debug!("waiting for response...");
-                // Wait for the result synchronously (could take 2+ minutes)
-                rx.recv().unwrap_or_else(|_| {
-                    Err(solana_sdk::signer::SignerError::Custom(
-                        "Channel closed".to_string(),
-                    ))
-                })
-            }
+            None => self
+                .sign_message_detached(message)
+                .map_err(|e| solana_sdk::signer::SignerError::Custom(format!("{e}"))),
         }
     }
 
     /// Indicates whether this signer requires user interaction.
     ///
-    /// Returns `true` because Fireblocks signing may require approval
-    /// workflows or other interactive elements depending on the vault
-    /// configuration and transaction policies.
+    /// Returns [`FireblocksSigner::interactive`], which defaults to `true`
+    /// because Fireblocks signing may require approval workflows depending on
+    /// the vault configuration and transaction policies. Keypair-backed
+    /// signers are never interactive.
     fn is_interactive(&self) -> bool {
-        true
+        self.interactive
     }
 }
 
 #[cfg(test)]
 mod test {
-    use {crate::PollConfig, std::time::Duration};
+    use {
+        super::*,
+        crate::{PollConfig, test_util::remote_signer},
+        solana_sdk::{
+            instruction::{AccountMeta, Instruction},
+            message::Message,
+        },
+        std::time::Duration,
+    };
+
+    fn co_signed_tx(payer: &Pubkey, co_signer: &Pubkey) -> VersionedTransaction {
+        let ix = Instruction::new_with_bytes(Pubkey::new_unique(), b"co-sign", vec![
+            AccountMeta::new_readonly(*co_signer, true),
+        ]);
+        VersionedTransaction::new_unsigned(VersionedMessage::Legacy(Message::new(
+            &[ix],
+            Some(payer),
+        )))
+    }
 
     #[test]
     fn test_poll() {
         let poll = PollConfig::default();
         assert_eq!(poll.timeout, Duration::from_secs(15));
+        assert_eq!("SILENT".parse::<PollLog>().ok(), Some(PollLog::Silent));
+        assert!("chatty".parse::<PollLog>().is_err());
+    }
+
+    #[test]
+    fn test_sign_wait() {
+        let mut signer = remote_signer(Pubkey::new_unique());
+        signer.create_retries = 1;
+        assert_eq!(
+            signer.sign_wait(),
+            2 * (signer.poll_config.timeout + Duration::from_secs(30))
+        );
+        signer.sign_timeout = Some(Duration::from_secs(5));
+        assert_eq!(signer.sign_wait(), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(FireblocksSigner::new().validate().is_ok());
+        let unconfigured = remote_signer(Pubkey::new_unique());
+        assert!(matches!(
+            unconfigured.validate(),
+            Err(Error::Misconfigured(_))
+        ));
+        let tx = VersionedTransaction::default();
+        assert!(matches!(
+            unconfigured.sign_versioned_transaction(&tx),
+            Err(Error::Misconfigured(_))
+        ));
+    }
+
+    #[test]
+    fn test_interactive() {
+        assert!(remote_signer(Pubkey::new_unique()).is_interactive());
+        assert!(!FireblocksSigner::new().is_interactive());
+    }
+
+    #[test]
+    fn test_keypair_not_exposed() {
+        let keypair = Keypair::new();
+        let secret = keypair.to_base58_string();
+        let signer = FireblocksSigner::new_with_keypair(keypair);
+        for rendered in [
+            format!("{signer:?}"),
+            format!("{:?}", signer.describe()),
+            format!("{:?}", signer.to_config()),
+        ] {
+            assert!(!rendered.contains(&secret));
+        }
+    }
+
+    #[test]
+    fn test_additional_signers() -> anyhow::Result<()> {
+        let payer = Pubkey::new_unique();
+        let co_signer = Keypair::new();
+        let co_signer_pk = co_signer.pubkey();
+        let signer = remote_signer(payer).with_additional_signers([co_signer]);
+        let mut tx = co_signed_tx(&payer, &co_signer_pk);
+        signer.partial_sign(&mut tx)?;
+        assert_eq!(tx.signatures[0], Signature::default());
+        assert!(tx.signatures[1].verify(co_signer_pk.as_ref(), &tx.message.serialize()));
+
+        assert_eq!(signer.signer_position(&tx)?, 0);
+        assert!(
+            remote_signer(Pubkey::new_unique())
+                .sign_transaction_full(&tx)
+                .is_err()
+        );
+
+        let stranger = remote_signer(payer).with_additional_signers([Keypair::new()]);
+        assert!(stranger.partial_sign(&mut tx).is_err());
+        Ok(())
     }
 }
@@ -0,0 +1,70 @@
+use {
+    super::{FireblocksSigner, PollConfig, SigningReceipt},
+    crate::{Error, Result},
+    solana_sdk::{signature::Signature, transaction::VersionedTransaction},
+    std::future::Future,
+};
+
+impl FireblocksSigner {
+    /// Signs a versioned transaction without blocking the async runtime.
+    ///
+    /// Behaves like [`FireblocksSigner::sign_versioned_transaction`], using
+    /// the signer's [`PollConfig`], but runs the blocking Fireblocks flow on
+    /// a background worker thread and resolves once it is done. Dropping the
+    /// future does not stop the sign.
+    ///
+    /// # Feature Flag
+    ///
+    /// Requires the `async` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use {
+    ///     fireblocks_solana_signer::FireblocksSigner,
+    ///     solana_sdk::transaction::VersionedTransaction,
+    /// };
+    ///
+    /// # async fn sign(signer: &FireblocksSigner, tx: &VersionedTransaction) -> anyhow::Result<()> {
+    /// let sig = signer.sign_versioned_transaction_async(tx).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn sign_versioned_transaction_async(
+        &self,
+        tx: &VersionedTransaction,
+    ) -> Result<Signature> {
+        self.sign_versioned_transaction_with_poll_async(tx, &self.poll_config)
+            .await
+    }
+
+    /// Async counterpart of
+    /// [`FireblocksSigner::sign_versioned_transaction_with_poll`].
+    pub async fn sign_versioned_transaction_with_poll_async(
+        &self,
+        tx: &VersionedTransaction,
+        poll: &PollConfig,
+    ) -> Result<Signature> {
+        self.sign_receipt_async(tx.clone(), poll.clone())
+            .await
+            .map(|r| r.signature)
+    }
+
+    /// Runs [`FireblocksSigner::sign_receipt`] on a worker thread.
+    pub(crate) fn sign_receipt_async(
+        &self,
+        tx: VersionedTransaction,
+        poll: PollConfig,
+    ) -> impl Future<Output = Result<SigningReceipt>> + Send + 'static {
+        let signer = self.clone();
+        let (sender, receiver) = tokio::sync::oneshot::channel();
+        self.workers.execute(move || {
+            let _ = sender.send(signer.sign_receipt(&tx, &poll));
+        });
+        async move {
+            receiver
+                .await
+                .unwrap_or_else(|_| Err(Error::ChannelClosed("Channel closed".to_string())))
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use std::{
+    sync::{Arc, Condvar, Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+/// How often a token created from a `tokio_util` `CancellationToken` is
+/// checked while a sign sleeps between polls.
+#[cfg(feature = "async")]
+const LINKED_CHECK: Duration = Duration::from_millis(100);
+
+/// A handle for aborting signs that are in progress.
+///
+/// Clones share state: cancelling any clone cancels every sign polling with
+/// a [`PollConfig`](crate::PollConfig) that holds one of them. A cancelled
+/// sign wakes from its sleep between polls right away and fails with
+/// [`Error::Cancelled`](crate::Error::Cancelled); if it had not yet been
+/// created at Fireblocks, it never is. A request to Fireblocks already under
+/// way is not interrupted, and while waiting on a
+/// [`WebhookListener`](crate::WebhookListener) the token is checked every
+/// [`PollConfig::interval`](crate::PollConfig::interval).
+///
+/// With the `async` feature, a `tokio_util` `CancellationToken` converts
+/// into a `CancelToken` that is cancelled along with it, within 100
+/// milliseconds. Cancelling the `CancelToken` cancels the
+/// `CancellationToken` too.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fireblocks_solana_signer::{CancelToken, FireblocksSigner, PollConfig};
+///
+/// # fn main() -> anyhow::Result<()> {
+/// let token = CancelToken::default();
+/// let signer = FireblocksSigner::try_from_env(None)?;
+/// let mut poll = signer.poll_config.clone();
+/// poll.cancel = Some(token.clone());
+/// // on shutdown or user abort, from any thread:
+/// token.cancel();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CancelToken {
+    state: Arc<State>,
+    #[cfg(feature = "async")]
+    linked: Option<tokio_util::sync::CancellationToken>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    cancelled: Mutex<bool>,
+    wake: Condvar,
+}
+
+impl CancelToken {
+    /// Cancels every sign using this token.
+    pub fn cancel(&self) {
+        *self
+            .state
+            .cancelled
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = true;
+        self.state.wake.notify_all();
+        #[cfg(feature = "async")]
+        if let Some(linked) = &self.linked {
+            linked.cancel();
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.linked_cancelled()
+            || *self
+                .state
+                .cancelled
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn linked_cancelled(&self) -> bool {
+        #[cfg(feature = "async")]
+        if let Some(linked) = &self.linked {
+            return linked.is_cancelled();
+        }
+        false
+    }
+
+    /// Sleeps for `duration`, waking early if the token is cancelled.
+    /// Returns whether it was.
+    pub(crate) fn sleep(&self, duration: Duration) -> bool {
+        let deadline = Instant::now() + duration;
+        #[cfg(feature = "async")]
+        let check = if self.linked.is_some() {
+            LINKED_CHECK
+        } else {
+            duration
+        };
+        #[cfg(not(feature = "async"))]
+        let check = duration;
+        let mut cancelled = self
+            .state
+            .cancelled
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        loop {
+            if *cancelled || self.linked_cancelled() {
+                return true;
+            }
+            let left = deadline.saturating_duration_since(Instant::now());
+            if left.is_zero() {
+                return false;
+            }
+            cancelled = self
+                .state
+                .wake
+                .wait_timeout(cancelled, left.min(check))
+                .unwrap_or_else(PoisonError::into_inner)
+                .0;
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl From<tokio_util::sync::CancellationToken> for CancelToken {
+    fn from(token: tokio_util::sync::CancellationToken) -> Self {
+        Self {
+            state: Arc::default(),
+            linked: Some(token),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{Error, PollConfig},
+    };
+
+    #[test]
+    fn test_cancel() {
+        let token = CancelToken::default();
+        let poll = PollConfig {
+            cancel: Some(token.clone()),
+            ..PollConfig::default()
+        };
+        assert!(poll.check_cancelled().is_ok());
+        token.cancel();
+        assert!(matches!(poll.check_cancelled(), Err(Error::Cancelled)));
+        assert!(poll.sleep(Duration::from_secs(60)).is_err());
+    }
+
+    #[test]
+    fn test_cancel_wakes_sleep() {
+        let token = CancelToken::default();
+        let canceller = token.clone();
+        let start = Instant::now();
+        std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(20));
+            canceller.cancel();
+        });
+        assert!(token.sleep(Duration::from_secs(60)));
+        assert!(start.elapsed() < Duration::from_secs(60));
+        assert!(!CancelToken::default().sleep(Duration::from_millis(1)));
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_cancellation_token() {
+        let tokio_token = tokio_util::sync::CancellationToken::new();
+        let token = CancelToken::from(tokio_token.clone());
+        assert!(!token.is_cancelled());
+        tokio_token.cancel();
+        assert!(token.is_cancelled());
+        assert!(token.sleep(Duration::from_secs(60)));
+    }
+}
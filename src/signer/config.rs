@@ -97,6 +97,7 @@ impl FireblocksSigner {
             .broadcast(false)
             .pk(pk)
             .client(client)
+            .endpoint(cfg.url.clone())
             .asset(asset)
             .vault_id(cfg.signer.vault)
             .poll_config(
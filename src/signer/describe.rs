@@ -0,0 +1,150 @@
+use {
+    super::FireblocksSigner,
+    crate::Asset,
+    solana_sdk::{pubkey::Pubkey, signature::Signer},
+    std::{
+        fmt::{Display, Formatter},
+        time::Duration,
+    },
+};
+
+/// A redacted snapshot of a [`FireblocksSigner`]'s configuration, returned by
+/// [`FireblocksSigner::describe`].
+///
+/// Contains no credentials, so it is safe to log at startup or attach to a
+/// support request.
+#[derive(Clone, Debug)]
+pub struct SignerDescription {
+    /// Version of this crate.
+    pub crate_version: &'static str,
+
+    /// Fireblocks API endpoint, if known.
+    pub endpoint: Option<String>,
+
+    /// Fireblocks vault id.
+    pub vault_id: String,
+
+    /// Fireblocks asset id.
+    pub asset: Asset,
+
+    /// The signer's public key.
+    pub pubkey: Pubkey,
+
+    /// Whether signing is done by a local keypair instead of Fireblocks.
+    pub local_keypair: bool,
+
+    /// Whether Fireblocks broadcasts signed transactions.
+    pub broadcast: bool,
+
+    /// Value reported by [`Signer::is_interactive`].
+    pub interactive: bool,
+
+    /// Poll timeout.
+    pub poll_timeout: Duration,
+
+    /// Poll interval.
+    pub poll_interval: Duration,
+
+    /// Pubkeys of the configured additional signers.
+    pub additional_signers: Vec<Pubkey>,
+
+    /// Cargo features this crate was compiled with.
+    pub features: Vec<&'static str>,
+}
+
+impl Display for SignerDescription {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "fireblocks-solana-signer v{} endpoint={} vault={} asset={} pubkey={} \
+             local_keypair={} broadcast={} interactive={} poll_timeout={:?} poll_interval={:?} \
+             additional_signers={} features=[{}]",
+            self.crate_version,
+            self.endpoint.as_deref().unwrap_or("unknown"),
+            self.vault_id,
+            self.asset,
+            self.pubkey,
+            self.local_keypair,
+            self.broadcast,
+            self.interactive,
+            self.poll_timeout,
+            self.poll_interval,
+            self.additional_signers.len(),
+            self.features.join(","),
+        )
+    }
+}
+
+/// Cargo features enabled at compile time.
+fn enabled_features() -> Vec<&'static str> {
+    [
+        ("async", cfg!(feature = "async")),
+        ("aws", cfg!(feature = "aws")),
+        ("config", cfg!(feature = "config")),
+        ("ffi", cfg!(feature = "ffi")),
+        ("gpg", cfg!(feature = "gpg")),
+        ("keyring", cfg!(feature = "keyring")),
+        ("metrics", cfg!(feature = "metrics")),
+        ("rustls-tls", cfg!(feature = "rustls-tls")),
+        ("sentry", cfg!(feature = "sentry")),
+        ("tower", cfg!(feature = "tower")),
+        ("watchdog", cfg!(feature = "watchdog")),
+        ("webhook", cfg!(feature = "webhook")),
+    ]
+    .into_iter()
+    .filter_map(|(name, enabled)| enabled.then_some(name))
+    .collect()
+}
+
+impl FireblocksSigner {
+    /// Returns a redacted snapshot of this signer's configuration for
+    /// diagnostics.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fireblocks_solana_signer::FireblocksSigner;
+    ///
+    /// # fn main() -> anyhow::Result<()> {
+    /// let signer = FireblocksSigner::try_from_env(None)?;
+    /// tracing::info!("{}", signer.describe());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn describe(&self) -> SignerDescription {
+        SignerDescription {
+            crate_version: env!("CARGO_PKG_VERSION"),
+            endpoint: self.endpoint.clone(),
+            vault_id: self.vault_id.clone(),
+            asset: self.asset.clone(),
+            pubkey: self.pk,
+            local_keypair: self.keypair.is_some(),
+            broadcast: self.broadcast,
+            interactive: self.is_interactive(),
+            poll_timeout: self.poll_config.timeout,
+            poll_interval: self.poll_config.interval,
+            additional_signers: self
+                .additional_signers
+                .iter()
+                .filter_map(|s| s.try_pubkey().ok())
+                .collect(),
+            features: enabled_features(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::test_util::remote_signer};
+
+    #[test]
+    fn test_describe() {
+        let pk = Pubkey::new_unique();
+        let description = remote_signer(pk).describe();
+        assert_eq!(description.pubkey, pk);
+        assert!(!description.local_keypair);
+        let line = description.to_string();
+        assert!(line.contains(&format!("pubkey={pk}")));
+        assert!(line.contains("vault=0"));
+    }
+}
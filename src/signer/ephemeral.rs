@@ -0,0 +1,92 @@
+use {
+    super::{FireblocksSigner, SigningReceipt},
+    crate::{Result, VersionedTransactionExtension},
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer, SignerError},
+        transaction::VersionedTransaction,
+    },
+};
+
+/// The outcome of [`FireblocksSigner::sign_with_ephemeral_keypair`].
+#[derive(Debug)]
+pub struct EphemeralSigned {
+    /// The pubkey of the generated keypair, e.g. the new mint or account
+    /// address.
+    pub address: Pubkey,
+
+    /// The transaction with every signature in place, ready to broadcast.
+    pub transaction: VersionedTransaction,
+
+    /// The Fireblocks receipt of the vault signature.
+    pub receipt: SigningReceipt,
+}
+
+impl FireblocksSigner {
+    /// Generates a one-off keypair, builds a transaction around its pubkey
+    /// and signs it with both the keypair and Fireblocks.
+    ///
+    /// This is the usual choreography for creating a mint (or any other
+    /// account that must sign its own creation) with a Fireblocks vault as
+    /// fee payer and authority:
+    ///
+    /// 1. a fresh [`Keypair`] is generated,
+    /// 2. `build` receives its pubkey and returns the unsigned transaction,
+    /// 3. the keypair and any
+    ///    [`additional_signers`](FireblocksSigner::additional_signers) sign,
+    /// 4. Fireblocks signs, and its signature is verified and placed in the
+    ///    slot of [`FireblocksSigner::pk`] (see
+    ///    [`FireblocksSigner::sign_in_place`]).
+    ///
+    /// The keypair is dropped once signing is done; it is never persisted.
+    ///
+    /// # Errors
+    ///
+    /// Fails before contacting Fireblocks if `build` fails or if either the
+    /// generated pubkey or [`FireblocksSigner::pk`] is not a required signer
+    /// of the transaction. Otherwise fails like
+    /// [`FireblocksSigner::sign_in_place`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use {
+    ///     fireblocks_solana_signer::{Error, FireblocksSigner, VersionedTransactionExtension},
+    ///     solana_sdk::{hash::Hash, instruction::Instruction, transaction::VersionedTransaction},
+    /// };
+    ///
+    /// # fn mint_instructions(mint: &solana_sdk::pubkey::Pubkey) -> Vec<Instruction> { vec![] }
+    /// # fn main() -> anyhow::Result<()> {
+    /// let signer = FireblocksSigner::try_from_env(None)?;
+    /// let blockhash = Hash::default(); // fetch a recent blockhash
+    /// let signed = signer.sign_with_ephemeral_keypair(|mint| {
+    ///     VersionedTransaction::new_unsigned_v0(&signer.pk, &mint_instructions(mint), &[], blockhash)
+    ///         .map_err(|e| Error::InvalidMessage(e.to_string()))
+    /// })?;
+    /// println!("created mint {}", signed.address);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn sign_with_ephemeral_keypair<F>(&self, build: F) -> Result<EphemeralSigned>
+    where
+        F: FnOnce(&Pubkey) -> Result<VersionedTransaction>,
+    {
+        let keypair = Keypair::new();
+        let address = keypair.pubkey();
+        let mut transaction = build(&address)?;
+        let ephemeral_position = transaction
+            .get_signing_keypair_positions(&[address])?
+            .first()
+            .copied()
+            .flatten()
+            .ok_or(SignerError::KeypairPubkeyMismatch)?;
+        transaction.signatures[ephemeral_position] =
+            keypair.try_sign_message(&transaction.message.serialize())?;
+        let receipt = self.sign_in_place(&mut transaction)?;
+        Ok(EphemeralSigned {
+            address,
+            transaction,
+            receipt,
+        })
+    }
+}
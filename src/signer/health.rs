@@ -0,0 +1,144 @@
+use std::time::{Duration, SystemTime};
+
+/// How long after a successful Fireblocks API call
+/// [`FireblocksSigner::health`] skips its own lookup.
+///
+/// [`FireblocksSigner::health`]: crate::FireblocksSigner::health
+pub(crate) const CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// A readiness snapshot of a [`FireblocksSigner`], returned by
+/// [`FireblocksSigner::health`].
+///
+/// [`FireblocksSigner`]: crate::FireblocksSigner
+/// [`FireblocksSigner::health`]: crate::FireblocksSigner::health
+#[derive(Clone, Debug)]
+pub struct Health {
+    /// Whether Fireblocks accepted the API credentials. Only an
+    /// authentication failure clears it; outages are reported in
+    /// [`Health::error`].
+    pub credentials_valid: bool,
+
+    /// Why the health check failed, if it did.
+    pub error: Option<String>,
+
+    /// When a Fireblocks API call last succeeded.
+    pub last_success: Option<SystemTime>,
+
+    /// Number of signs currently waiting on Fireblocks.
+    pub in_flight: usize,
+
+    /// Whether [`FireblocksSigner::shutdown`] was called.
+    ///
+    /// [`FireblocksSigner::shutdown`]: crate::FireblocksSigner::shutdown
+    pub shutting_down: bool,
+
+    /// Why the signer was flagged as degraded by a background watchdog, if
+    /// it was.
+    pub degraded: Option<String>,
+
+    /// State of the circuit breaker guarding transaction creation, always
+    /// [`Breaker::Closed`] unless
+    /// [`FireblocksSigner::breaker`](crate::FireblocksSigner::breaker) is
+    /// set.
+    pub breaker: Breaker,
+
+    /// Number of creates that failed in a row.
+    pub consecutive_failures: u32,
+}
+
+impl Health {
+    /// Whether the signer can accept new signing requests.
+    pub fn is_ready(&self) -> bool {
+        self.credentials_valid
+            && self.error.is_none()
+            && !self.shutting_down
+            && self.degraded.is_none()
+            && self.breaker != Breaker::Open
+    }
+}
+
+/// Settings of the opt-in circuit breaker of a [`FireblocksSigner`], see
+/// [`FireblocksSigner::breaker`].
+///
+/// After `threshold` creates fail in a row the breaker opens, and signs fail
+/// with [`Error::CircuitOpen`] without calling Fireblocks for `cooldown`.
+/// Then it half-opens: a single create goes through as a probe while the
+/// others keep failing fast. The breaker closes if the probe succeeds and
+/// opens again if it fails.
+///
+/// # Examples
+///
+/// ```
+/// use {fireblocks_solana_signer::BreakerConfig, std::time::Duration};
+///
+/// let breaker = BreakerConfig {
+///     threshold: 5,
+///     cooldown: Duration::from_secs(30),
+/// };
+/// ```
+///
+/// [`FireblocksSigner`]: crate::FireblocksSigner
+/// [`FireblocksSigner::breaker`]: crate::FireblocksSigner::breaker
+/// [`Error::CircuitOpen`]: crate::Error::CircuitOpen
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BreakerConfig {
+    /// Consecutive failed creates after which the breaker opens.
+    pub threshold: u32,
+
+    /// How long the breaker stays open before letting a probe through.
+    pub cooldown: Duration,
+}
+
+/// State of the circuit breaker of a [`FireblocksSigner`] and its clones,
+/// see [`BreakerConfig`].
+///
+/// [`FireblocksSigner`]: crate::FireblocksSigner
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Breaker {
+    /// Creates go through.
+    #[default]
+    Closed,
+
+    /// Creates fail fast, or the probe create is in flight.
+    Open,
+
+    /// The cooldown passed; the next create is the probe.
+    HalfOpen,
+}
+
+/// Transactions created at Fireblocks by broadcast mode, returned by
+/// [`FireblocksSigner::created_counts`].
+///
+/// [`FireblocksSigner::created_counts`]: crate::FireblocksSigner::created_counts
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CreatedCounts {
+    /// Transactions Fireblocks signed and broadcast.
+    pub broadcast: u64,
+
+    /// Transactions Fireblocks only signed.
+    pub sign_only: u64,
+}
+
+/// Runtime statistics of a [`FireblocksSigner`] and its clones, returned by
+/// [`FireblocksSigner::stats`].
+///
+/// [`FireblocksSigner`]: crate::FireblocksSigner
+/// [`FireblocksSigner::stats`]: crate::FireblocksSigner::stats
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SignerStats {
+    /// Signs started through Fireblocks.
+    pub attempted: u64,
+
+    /// Signs that returned a signature.
+    pub succeeded: u64,
+
+    /// Signs that returned an error.
+    pub failed: u64,
+
+    /// How long the most recently finished sign took, creation and polling
+    /// included.
+    pub last_duration: Option<Duration>,
+
+    /// Number of signs currently waiting on Fireblocks.
+    pub in_flight: usize,
+}
@@ -26,10 +26,20 @@
 //! `solana_sdk::signature::Keypair`, providing the same function signatures and
 //! behavior. This ensures that existing code using Solana's Keypair can work
 //! unchanged when switched to FireblocksSigner.
+//!
+//! The exception is exporting the secret. A Fireblocks-backed signer has no
+//! local key, so [`FireblocksSigner::secret_bytes`],
+//! [`FireblocksSigner::to_bytes`] and [`FireblocksSigner::to_base58_string`]
+//! return a [`Result`] where `Keypair` returns the value directly. The latter
+//! two also wrap the copy in [`Zeroizing`], which dereferences to the
+//! `Keypair` type. Code calling them needs a `?` or an `expect` after the
+//! swap, plus a `*` where the exact type is required.
 
 use {
     super::FireblocksSigner,
+    crate::{Error, Result},
     solana_sdk::{signature::Keypair, signer::Signer},
+    zeroize::Zeroizing,
 };
 
 /// The length of a keypair in bytes (32 bytes secret key + 32 bytes public key)
@@ -115,27 +125,28 @@ impl FireblocksSigner {
         }
     }
 
+    /// Constructs a `FireblocksSigner` from a 32-byte secret key.
+    ///
+    /// This method provides compatibility with Solana's
+    /// `Keypair::new_from_array()` function, enabling seamless replacement
+    /// when using feature flags.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use {
+    ///     fireblocks_solana_signer::FireblocksSigner,
+    ///     solana_sdk::signature::{Keypair, Signer},
+    /// };
+    ///
+    /// let keypair = Keypair::new();
+    /// let signer = FireblocksSigner::new_from_array(*keypair.secret_bytes());
+    /// assert_eq!(signer.pubkey(), keypair.pubkey());
+    /// ```
     pub fn new_from_array(secret_key: [u8; 32]) -> Self {
-        let kp = Keypair::new_from_array(secret_key);
-        Self::new_with_keypair(kp)
+        Self::new_with_keypair(Keypair::new_from_array(secret_key))
     }
 
-    // /// Constructs a `FireblocksSigner` from a 32-byte secret key array.
-    // ///
-    // /// This method provides compatibility with Solana's
-    // `Keypair::from_secret_key_bytes()` function. /// Currently commented out
-    // but can be enabled if needed for full Keypair compatibility. ///
-    // /// # Arguments
-    // ///
-    // /// * `secret_key` - A 32-byte array containing the secret key
-    // ///
-    // /// # Returns
-    // ///
-    // /// A new `FireblocksSigner` instance created from the secret key.
-    // pub fn new_from_array(secret_key: [u8; 32]) -> Self {
-    //     Self::new_with_keypair(Keypair::new_from_array(secret_key))
-    // }
-
     /// Returns the keypair as a byte array.
     ///
     /// This method provides compatibility with Solana's `Keypair::to_bytes()`
@@ -144,8 +155,12 @@ impl FireblocksSigner {
     /// # Returns
     ///
     /// A 64-byte array containing the keypair data (32 bytes secret key + 32
-    /// bytes public key). If no keypair is available, returns bytes from a
-    /// newly generated keypair.
+    /// bytes public key), wiped when dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NoKeypair`] if the signer is backed by Fireblocks,
+    /// since the vault key never leaves Fireblocks.
     ///
     /// # Example
     ///
@@ -153,14 +168,12 @@ impl FireblocksSigner {
     /// use fireblocks_solana_signer::FireblocksSigner;
     ///
     /// let signer = FireblocksSigner::new();
-    /// let bytes = signer.to_bytes();
+    /// let bytes = signer.to_bytes().expect("local keypair");
     /// assert_eq!(bytes.len(), 64);
     /// ```
-    pub fn to_bytes(&self) -> [u8; KEYPAIR_LENGTH] {
-        match &self.keypair {
-            Some(kp) => kp.to_bytes(),
-            None => Keypair::new().to_bytes(),
-        }
+    pub fn to_bytes(&self) -> Result<Zeroizing<[u8; KEYPAIR_LENGTH]>> {
+        let kp = self.keypair.as_ref().ok_or(Error::NoKeypair)?;
+        Ok(Zeroizing::new(kp.to_bytes()))
     }
 
     /// Constructs a `FireblocksSigner` from a base58-encoded string.
@@ -198,9 +211,12 @@ impl FireblocksSigner {
     ///
     /// # Returns
     ///
-    /// A base58-encoded string representation of the keypair.
-    /// If no keypair is available, returns a string from a newly generated
-    /// keypair.
+    /// A base58-encoded string representation of the keypair, wiped when
+    /// dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NoKeypair`] if the signer is backed by Fireblocks.
     ///
     /// # Example
     ///
@@ -208,14 +224,50 @@ impl FireblocksSigner {
     /// use fireblocks_solana_signer::FireblocksSigner;
     ///
     /// let signer = FireblocksSigner::new();
-    /// let base58_string = signer.to_base58_string();
-    /// println!("Keypair as base58: {}", base58_string);
+    /// let base58_string = signer.to_base58_string().expect("local keypair");
     /// ```
-    pub fn to_base58_string(&self) -> String {
-        match &self.keypair {
-            Some(kp) => kp.to_base58_string(),
-            None => Keypair::new().to_base58_string(),
-        }
+    pub fn to_base58_string(&self) -> Result<Zeroizing<String>> {
+        let kp = self.keypair.as_ref().ok_or(Error::NoKeypair)?;
+        Ok(Zeroizing::new(kp.to_base58_string()))
+    }
+
+    /// Constructs a `FireblocksSigner` from a base58-encoded string, failing
+    /// instead of panicking on invalid input.
+    ///
+    /// This method provides compatibility with Solana's
+    /// `Keypair::try_from_base58_string()` function.
+    pub fn try_from_base58_string(
+        s: &str,
+    ) -> std::result::Result<Self, Box<dyn std::error::Error>> {
+        Ok(Self::new_with_keypair(Keypair::try_from_base58_string(s)?))
+    }
+
+    /// Returns the 32-byte secret key of the wrapped keypair.
+    ///
+    /// This method provides compatibility with Solana's
+    /// `Keypair::secret_bytes()` function, but is fallible.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NoKeypair`] if the signer is backed by Fireblocks
+    /// rather than a local keypair, since the vault key never leaves
+    /// Fireblocks.
+    pub fn secret_bytes(&self) -> Result<&[u8; 32]> {
+        Ok(self
+            .keypair
+            .as_ref()
+            .ok_or(Error::NoKeypair)?
+            .secret_bytes())
+    }
+
+    /// Returns a copy of this signer.
+    ///
+    /// This method provides compatibility with Solana's
+    /// `Keypair::insecure_clone()` function. Unlike a `Keypair`, a
+    /// `FireblocksSigner` is cheap to [`Clone`] and clones share the same
+    /// keypair or Fireblocks client, so this is equivalent to `clone()`.
+    pub fn insecure_clone(&self) -> Self {
+        self.clone()
     }
 }
 
@@ -243,4 +295,35 @@ mod tests {
         // Both signers should have the same public key
         assert_eq!(signer1.pubkey(), signer2.pubkey());
     }
+
+    #[test]
+    fn test_keypair_parity() {
+        let keypair = Keypair::new();
+        let signer = FireblocksSigner::new_with_keypair(keypair.insecure_clone());
+        assert_eq!(
+            signer.secret_bytes().expect("local keypair"),
+            keypair.secret_bytes()
+        );
+        assert_eq!(signer.insecure_clone().pubkey(), keypair.pubkey());
+        assert_eq!(
+            signer.sign_message(b"parity"),
+            keypair.sign_message(b"parity")
+        );
+        let parsed = FireblocksSigner::try_from_base58_string(&keypair.to_base58_string())
+            .expect("valid base58 keypair");
+        assert_eq!(parsed.pubkey(), keypair.pubkey());
+        assert!(FireblocksSigner::try_from_base58_string("not a keypair").is_err());
+        assert_eq!(
+            *signer.to_base58_string().expect("local keypair"),
+            keypair.to_base58_string()
+        );
+    }
+
+    #[test]
+    fn test_no_keypair_export() {
+        let signer = FireblocksSigner::default();
+        assert!(matches!(signer.to_bytes(), Err(Error::NoKeypair)));
+        assert!(matches!(signer.to_base58_string(), Err(Error::NoKeypair)));
+        assert!(matches!(signer.secret_bytes(), Err(Error::NoKeypair)));
+    }
 }
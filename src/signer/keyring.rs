@@ -0,0 +1,54 @@
+use {super::*, ::keyring::Entry, zeroize::Zeroizing};
+
+impl FireblocksSigner {
+    /// Creates a new `FireblocksSigner` with the API credentials stored in
+    /// the OS keychain (macOS Keychain, Windows Credential Manager or the
+    /// Secret Service on Linux).
+    ///
+    /// # Feature Flag
+    ///
+    /// This function is only available when the `keyring` feature is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `service` - The keychain service, e.g. `fireblocks`.
+    /// * `user` - The keychain user. Its password is the API key, and the
+    ///   password of `{user}/secret` is the RSA secret in any form accepted by
+    ///   [`secret_pem`](crate::secret_pem).
+    ///
+    /// Everything else is read from the environment exactly like
+    /// [`FireblocksSigner::try_from_env`], except `FIREBLOCKS_API_KEY`,
+    /// `FIREBLOCKS_SECRET` and `FIREBLOCKS_SECRET_PATH`, which are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Keyring`] if either entry is missing or the keychain
+    /// is locked, otherwise fails like [`FireblocksSigner::try_from_env`].
+    ///
+    /// # Examples
+    ///
+    /// Store the credentials once, e.g. on macOS:
+    ///
+    /// ```shell
+    /// security add-generic-password -s fireblocks -a dev -w "$API_KEY"
+    /// security add-generic-password -s fireblocks -a dev/secret -w "$(base64 -i fireblocks.pem)"
+    /// ```
+    ///
+    /// then:
+    ///
+    /// ```no_run
+    /// use fireblocks_solana_signer::FireblocksSigner;
+    ///
+    /// # fn main() -> anyhow::Result<()> {
+    /// let signer = FireblocksSigner::try_from_keyring("fireblocks", "dev")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_from_keyring(service: &str, user: &str) -> Result<Self> {
+        let api_key = Zeroizing::new(Entry::new(service, user)?.get_password()?);
+        let secret =
+            Zeroizing::new(Entry::new(service, &format!("{user}/secret"))?.get_password()?);
+        let builder = crate::client_env::with_credentials(&api_key, secret.as_bytes())?;
+        Self::from_env_with_client(builder, None)
+    }
+}
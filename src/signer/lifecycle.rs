@@ -0,0 +1,293 @@
+use {
+    super::{Breaker, BreakerConfig, SignerStats},
+    crate::{Error, Result},
+    std::{
+        sync::{
+            Condvar,
+            Mutex,
+            PoisonError,
+            atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering},
+        },
+        time::{Duration, Instant, SystemTime},
+    },
+};
+
+/// Tracks in-flight signs so a signer (and all of its clones) can be shut
+/// down gracefully.
+#[derive(Debug, Default)]
+pub(crate) struct Lifecycle {
+    closed: AtomicBool,
+    in_flight: Mutex<usize>,
+    drained: Condvar,
+    last_success: Mutex<Option<SystemTime>>,
+    failures: AtomicU32,
+    opened_at: Mutex<Option<Instant>>,
+    probing: AtomicBool,
+    degraded: Mutex<Option<String>>,
+    broadcast: AtomicU64,
+    sign_only: AtomicU64,
+    attempted: AtomicU64,
+    succeeded: AtomicU64,
+    failed: AtomicU64,
+    last_duration: Mutex<Option<Duration>>,
+}
+
+/// Marks one sign as in flight until dropped.
+pub(crate) struct InFlight<'a>(&'a Lifecycle);
+
+impl Lifecycle {
+    /// Registers a new sign, failing once [`Lifecycle::close`] was called.
+    pub(crate) fn begin(&self) -> Result<InFlight<'_>> {
+        let mut in_flight = self
+            .in_flight
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if self.closed.load(Ordering::Acquire) {
+            return Err(Error::ShuttingDown);
+        }
+        *in_flight += 1;
+        Ok(InFlight(self))
+    }
+
+    pub(crate) fn in_flight(&self) -> usize {
+        *self
+            .in_flight
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub(crate) fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::Acquire)
+    }
+
+    /// Records a successful Fireblocks API call, closing the circuit
+    /// breaker.
+    pub(crate) fn record_success(&self) {
+        *self
+            .last_success
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(SystemTime::now());
+        self.failures.store(0, Ordering::Relaxed);
+        *self
+            .opened_at
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = None;
+        self.probing.store(false, Ordering::Release);
+    }
+
+    /// Records a failed create, opening the circuit breaker once
+    /// `breaker.threshold` failed in a row.
+    pub(crate) fn record_failure(&self, breaker: Option<BreakerConfig>) {
+        let failures = self.failures.fetch_add(1, Ordering::Relaxed) + 1;
+        if breaker.is_some_and(|b| failures >= b.threshold) {
+            *self
+                .opened_at
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner) = Some(Instant::now());
+        }
+        self.probing.store(false, Ordering::Release);
+    }
+
+    /// Fails fast while the circuit breaker is open. Once the cooldown has
+    /// passed, lets a single create through as a probe and keeps failing the
+    /// others until it completes.
+    pub(crate) fn check_breaker(&self, breaker: Option<BreakerConfig>) -> Result<()> {
+        let Some(breaker) = breaker else {
+            return Ok(());
+        };
+        let opened_at = *self
+            .opened_at
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let Some(at) = opened_at else {
+            return Ok(());
+        };
+        if at.elapsed() < breaker.cooldown {
+            return Err(Error::CircuitOpen(format!(
+                "{} creates failed in a row, retrying in {:?}",
+                self.failures(),
+                breaker.cooldown.saturating_sub(at.elapsed())
+            )));
+        }
+        self.probing
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .map(|_| ())
+            .map_err(|_| Error::CircuitOpen("a probe create is in flight".to_string()))
+    }
+
+    pub(crate) fn failures(&self) -> u32 {
+        self.failures.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn breaker(&self, breaker: Option<BreakerConfig>) -> Breaker {
+        let opened_at = *self
+            .opened_at
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        match (breaker, opened_at) {
+            (Some(breaker), Some(at))
+                if at.elapsed() < breaker.cooldown || self.probing.load(Ordering::Acquire) =>
+            {
+                Breaker::Open
+            }
+            (Some(_), Some(_)) => Breaker::HalfOpen,
+            _ => Breaker::Closed,
+        }
+    }
+
+    pub(crate) fn last_success(&self) -> Option<SystemTime> {
+        *self
+            .last_success
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Counts a transaction created at Fireblocks.
+    pub(crate) fn record_created(&self, broadcast: bool) {
+        let counter = if broadcast {
+            &self.broadcast
+        } else {
+            &self.sign_only
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn created_counts(&self) -> (u64, u64) {
+        (
+            self.broadcast.load(Ordering::Relaxed),
+            self.sign_only.load(Ordering::Relaxed),
+        )
+    }
+
+    /// Records the outcome of one sign that took `duration`.
+    pub(crate) fn record_sign(&self, ok: bool, duration: Duration) {
+        self.attempted.fetch_add(1, Ordering::Relaxed);
+        let counter = if ok { &self.succeeded } else { &self.failed };
+        counter.fetch_add(1, Ordering::Relaxed);
+        *self
+            .last_duration
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(duration);
+    }
+
+    pub(crate) fn stats(&self) -> SignerStats {
+        SignerStats {
+            attempted: self.attempted.load(Ordering::Relaxed),
+            succeeded: self.succeeded.load(Ordering::Relaxed),
+            failed: self.failed.load(Ordering::Relaxed),
+            last_duration: *self
+                .last_duration
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner),
+            in_flight: self.in_flight(),
+        }
+    }
+
+    /// Sets or clears the degraded reason, returning the previous one.
+    pub(crate) fn set_degraded(&self, reason: Option<String>) -> Option<String> {
+        std::mem::replace(
+            &mut *self.degraded.lock().unwrap_or_else(PoisonError::into_inner),
+            reason,
+        )
+    }
+
+    pub(crate) fn degraded(&self) -> Option<String> {
+        self.degraded
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Stops accepting new signs and waits up to `timeout` for in-flight
+    /// signs to finish.
+    pub(crate) fn close(&self, timeout: Duration) -> Result<()> {
+        let in_flight = self
+            .in_flight
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        self.closed.store(true, Ordering::Release);
+        let (in_flight, wait) = self
+            .drained
+            .wait_timeout_while(in_flight, timeout, |n| *n > 0)
+            .unwrap_or_else(PoisonError::into_inner);
+        if wait.timed_out() {
+            return Err(Error::Timeout(format!(
+                "{} signing request(s) still in flight after {timeout:?}",
+                *in_flight
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl Drop for InFlight<'_> {
+    fn drop(&mut self) {
+        let mut in_flight = self
+            .0
+            .in_flight
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        *in_flight -= 1;
+        if *in_flight == 0 {
+            self.0.drained.notify_all();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lifecycle() {
+        let lifecycle = Lifecycle::default();
+        let guard = lifecycle.begin().expect("open lifecycle accepts signs");
+        assert_eq!(lifecycle.in_flight(), 1);
+        assert!(lifecycle.close(Duration::from_millis(10)).is_err());
+        assert!(matches!(lifecycle.begin(), Err(Error::ShuttingDown)));
+        lifecycle.record_sign(true, Duration::from_secs(2));
+        assert_eq!(lifecycle.stats().in_flight, 1);
+        drop(guard);
+        assert_eq!(lifecycle.in_flight(), 0);
+        let stats = lifecycle.stats();
+        assert_eq!((stats.attempted, stats.succeeded, stats.failed), (1, 1, 0));
+        assert_eq!(stats.last_duration, Some(Duration::from_secs(2)));
+        assert!(lifecycle.close(Duration::from_millis(10)).is_ok());
+    }
+
+    #[test]
+    fn test_breaker() {
+        let lifecycle = Lifecycle::default();
+        lifecycle.record_failure(None);
+        assert_eq!(lifecycle.failures(), 1);
+        assert_eq!(lifecycle.breaker(None), Breaker::Closed);
+        assert!(lifecycle.check_breaker(None).is_ok());
+
+        let config = Some(BreakerConfig {
+            threshold: 2,
+            cooldown: Duration::ZERO,
+        });
+        let open = Some(BreakerConfig {
+            threshold: 2,
+            cooldown: Duration::from_secs(60),
+        });
+        lifecycle.record_failure(config);
+        assert_eq!(lifecycle.breaker(open), Breaker::Open);
+        assert!(matches!(
+            lifecycle.check_breaker(open),
+            Err(Error::CircuitOpen(_))
+        ));
+
+        // past the cooldown only one probe is let through
+        assert_eq!(lifecycle.breaker(config), Breaker::HalfOpen);
+        assert!(lifecycle.check_breaker(config).is_ok());
+        assert!(lifecycle.check_breaker(config).is_err());
+        lifecycle.record_failure(config);
+        assert!(lifecycle.check_breaker(config).is_ok());
+
+        lifecycle.record_success();
+        assert_eq!(lifecycle.breaker(open), Breaker::Closed);
+        assert_eq!(lifecycle.failures(), 0);
+        assert!(lifecycle.check_breaker(open).is_ok());
+    }
+}
@@ -0,0 +1,83 @@
+use {
+    super::{BreakerConfig, PollConfig},
+    solana_sdk::pubkey::Pubkey,
+    std::time::Duration,
+};
+
+/// Per-call overrides for
+/// [`FireblocksSigner::sign_versioned_transaction_with`](crate::FireblocksSigner::sign_versioned_transaction_with).
+///
+/// Unset fields fall back to the signer's own settings.
+///
+/// There are no fee level, note or external transaction id overrides. The
+/// transport creates Solana transactions from the asset, vault and encoded
+/// transaction alone, so those request fields cannot be set per call, nor
+/// per signer.
+///
+/// # Examples
+///
+/// ```
+/// use fireblocks_solana_signer::SignOptions;
+///
+/// let sign_only = SignOptions::builder().broadcast(false).build();
+/// ```
+#[derive(Clone, Debug, Default, bon::Builder)]
+pub struct SignOptions {
+    /// Overrides [`FireblocksSigner::broadcast`](crate::FireblocksSigner::broadcast).
+    pub broadcast: Option<bool>,
+
+    /// Overrides [`FireblocksSigner::poll_config`](crate::FireblocksSigner::poll_config).
+    pub poll_config: Option<PollConfig>,
+}
+
+/// Optional settings of
+/// [`FireblocksSigner::try_new`](crate::FireblocksSigner::try_new).
+///
+/// # Examples
+///
+/// ```
+/// use fireblocks_solana_signer::{FIREBLOCKS_SANDBOX_API, SignerOptions};
+///
+/// let options = SignerOptions::builder()
+///     .endpoint(FIREBLOCKS_SANDBOX_API)
+///     .broadcast(true)
+///     .build();
+/// ```
+#[derive(Clone, Debug, bon::Builder)]
+pub struct SignerOptions {
+    /// The Fireblocks API endpoint.
+    #[builder(into, default = crate::FIREBLOCKS_API.to_string())]
+    pub endpoint: String,
+
+    /// The vault's pubkey. Looked up from Fireblocks when absent.
+    pub pubkey: Option<Pubkey>,
+
+    /// HTTP timeout of the Fireblocks client.
+    #[builder(default = Duration::from_secs(crate::DEFAULT_CLIENT_TIMEOUT.into()))]
+    pub client_timeout: Duration,
+
+    /// See [`FireblocksSigner::poll_config`](crate::FireblocksSigner::poll_config).
+    #[builder(default)]
+    pub poll_config: PollConfig,
+
+    /// See [`FireblocksSigner::broadcast`](crate::FireblocksSigner::broadcast).
+    #[builder(default)]
+    pub broadcast: bool,
+
+    /// See [`FireblocksSigner::interactive`](crate::FireblocksSigner::interactive).
+    #[builder(default = true)]
+    pub interactive: bool,
+
+    /// See [`FireblocksSigner::create_retries`](crate::FireblocksSigner::create_retries).
+    #[builder(default)]
+    pub create_retries: u8,
+
+    /// See [`FireblocksSigner::breaker`](crate::FireblocksSigner::breaker).
+    pub breaker: Option<BreakerConfig>,
+}
+
+impl Default for SignerOptions {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
@@ -1,4 +1,12 @@
-use std::{fmt::Debug, time::Duration};
+use {
+    super::{CancelToken, PollEventHook},
+    crate::{TransactionResponse, TransactionStatus},
+    std::{
+        fmt::Debug,
+        sync::Arc,
+        time::{Duration, Instant},
+    },
+};
 /// Configuration for polling Fireblocks transaction status.
 ///
 /// This struct controls how the signer polls Fireblocks for transaction
@@ -8,12 +16,17 @@ use std::{fmt::Debug, time::Duration};
 /// # Examples
 ///
 /// ```
-/// use {fireblocks_solana_signer::PollConfig, std::time::Duration};
+/// use {
+///     fireblocks_solana_signer::{PollConfig, TransactionResponse},
+///     std::time::Duration,
+/// };
 ///
 /// let config = PollConfig::builder()
 ///     .timeout(Duration::from_secs(30))
 ///     .interval(Duration::from_secs(2))
 ///     .callback(|response| println!("Transaction status: {:?}", response))
+///     .on_blocked(|response: &TransactionResponse| eprintln!("Transaction blocked: {response}"))
+///     .warn_after(Duration::from_secs(10))
 ///     .build();
 /// ```
 #[derive(Clone, Debug, bon::Builder)]
@@ -30,12 +43,92 @@ pub struct PollConfig {
     /// with Fireblocks.
     pub interval: Duration,
 
+    /// How the interval changes while polling, see [`PollStrategy`].
+    #[builder(default)]
+    pub strategy: PollStrategy,
+
     /// Callback function called on each polling iteration.
     ///
     /// This function receives the current transaction response and can be used
     /// for logging, monitoring, or other side effects during the polling
     /// process.
     pub callback: fn(&crate::TransactionResponse),
+
+    /// Callback invoked when a transaction ends blocked by policy or rejected
+    /// (including AML screening rejections).
+    ///
+    /// The full [`TransactionResponse`](crate::TransactionResponse) is passed
+    /// so the hook can extract whatever approver or rejecter details
+    /// Fireblocks returned, e.g. to page the right people.
+    #[builder(into)]
+    pub on_blocked: Option<PollHook>,
+
+    /// How long a transaction may stay pending before it is reported as slow.
+    ///
+    /// Once exceeded, a warning is logged and [`PollConfig::on_slow`] is
+    /// invoked, while polling continues until [`PollConfig::timeout`]. This
+    /// gives operators early notice that an approval is stuck. Ignored if not
+    /// shorter than `timeout`.
+    pub warn_after: Option<Duration>,
+
+    /// Callback invoked once when [`PollConfig::warn_after`] elapses with the
+    /// transaction still pending.
+    #[builder(into)]
+    pub on_slow: Option<PollHook>,
+
+    /// How long a transaction may stay `BROADCASTING` before it is reported.
+    ///
+    /// A transaction that lingers in `BROADCASTING` usually means Fireblocks
+    /// has trouble reaching the Solana network. When set, polling continues
+    /// past `BROADCASTING` for up to this long; if the status has not moved
+    /// on by then, a warning is logged and [`PollConfig::on_broadcasting`] is
+    /// invoked. Only relevant for broadcasting signers.
+    pub broadcasting_after: Option<Duration>,
+
+    /// Callback invoked once when [`PollConfig::broadcasting_after`] elapses
+    /// with the transaction still broadcasting, e.g. to start an independent
+    /// on-chain check of the signature.
+    #[builder(into)]
+    pub on_broadcasting: Option<PollHook>,
+
+    /// A hard deadline for the whole sign, creation and polling included.
+    ///
+    /// Polling stops at the deadline even if [`PollConfig::timeout`] has not
+    /// elapsed, and no transaction is created once it has passed. Usually set
+    /// per call through
+    /// [`FireblocksSigner::sign_with_deadline`](crate::FireblocksSigner::sign_with_deadline).
+    pub deadline: Option<Instant>,
+
+    /// Aborts the sign when cancelled, see [`CancelToken`].
+    pub cancel: Option<CancelToken>,
+
+    /// A closure invoked with the latest response after each poll interval.
+    ///
+    /// Unlike [`PollConfig::callback`] it may capture state, such as a
+    /// channel or a metrics handle. Setting it makes polling proceed in
+    /// slices of one [`PollConfig::interval`].
+    #[builder(into)]
+    pub on_update: Option<PollHook>,
+
+    /// Invoked once the transaction is created at Fireblocks.
+    #[builder(into)]
+    pub on_submitted: Option<PollEventHook>,
+
+    /// Invoked whenever polling sees a new status, e.g. to show progress or
+    /// to detect approvals stuck in `PENDING_AUTHORIZATION`. Setting it makes
+    /// polling proceed in slices of one [`PollConfig::interval`].
+    #[builder(into)]
+    pub on_status_change: Option<PollEventHook>,
+
+    /// Invoked with the response polling ended with.
+    #[builder(into)]
+    pub on_final: Option<PollEventHook>,
+
+    /// Waits for status webhooks instead of polling every
+    /// [`PollConfig::interval`], see
+    /// [`WebhookListener`](crate::WebhookListener).
+    #[cfg(feature = "webhook")]
+    pub webhook: Option<crate::WebhookListener>,
 }
 
 impl Default for PollConfig {
@@ -44,11 +137,310 @@ impl Default for PollConfig {
     /// Default values:
     /// - `timeout`: 15 seconds
     /// - `interval`: 5 seconds
+    /// - `callback`: [`PollLog::Info`]
     fn default() -> Self {
         Self {
             timeout: Duration::from_secs(15),
             interval: Duration::from_secs(5),
-            callback: |t| tracing::info!("{t}"),
+            strategy: PollStrategy::Fixed,
+            callback: PollLog::Info.callback(),
+            on_blocked: None,
+            warn_after: None,
+            on_slow: None,
+            broadcasting_after: None,
+            on_broadcasting: None,
+            deadline: None,
+            cancel: None,
+            on_update: None,
+            on_submitted: None,
+            on_status_change: None,
+            on_final: None,
+            #[cfg(feature = "webhook")]
+            webhook: None,
+        }
+    }
+}
+
+impl PollConfig {
+    /// How long polling may still run: [`PollConfig::timeout`], capped by
+    /// the time left until [`PollConfig::deadline`].
+    pub(crate) fn remaining(&self) -> Duration {
+        self.deadline.map_or(self.timeout, |deadline| {
+            self.timeout
+                .min(deadline.saturating_duration_since(Instant::now()))
+        })
+    }
+
+    /// Fails with [`Error::Timeout`](crate::Error::Timeout) if the deadline
+    /// has passed.
+    pub(crate) fn check_deadline(&self) -> crate::Result<()> {
+        match self.deadline {
+            Some(deadline) if Instant::now() >= deadline => Err(crate::Error::Timeout(
+                "signing deadline passed before the transaction was created".to_string(),
+            )),
+            _ => Ok(()),
         }
     }
+
+    /// Whether polling has to run in slices of one interval, so the signer
+    /// sees intermediate responses. With the `metrics` feature it always
+    /// does, to time every status request on its own.
+    pub(crate) fn is_sliced(&self) -> bool {
+        cfg!(feature = "metrics")
+            || self.cancel.is_some()
+            || self.on_update.is_some()
+            || self.on_status_change.is_some()
+            || self.strategy != PollStrategy::Fixed
+    }
+
+    /// Passes `response` to [`PollConfig::on_update`], if set.
+    pub(crate) fn notify(&self, response: &TransactionResponse) {
+        if let Some(on_update) = &self.on_update {
+            on_update.call(response);
+        }
+    }
+
+    /// Fails with [`Error::Cancelled`](crate::Error::Cancelled) if the sign
+    /// was cancelled.
+    pub(crate) fn check_cancelled(&self) -> crate::Result<()> {
+        if self.cancel.as_ref().is_some_and(CancelToken::is_cancelled) {
+            return Err(crate::Error::Cancelled);
+        }
+        Ok(())
+    }
+
+    /// Sleeps for `duration` between polls, failing with
+    /// [`Error::Cancelled`](crate::Error::Cancelled) as soon as the sign is
+    /// cancelled.
+    pub(crate) fn sleep(&self, duration: Duration) -> crate::Result<()> {
+        match &self.cancel {
+            Some(cancel) if cancel.sleep(duration) => Err(crate::Error::Cancelled),
+            Some(_) => Ok(()),
+            None => {
+                std::thread::sleep(duration);
+                Ok(())
+            }
+        }
+    }
+}
+
+/// How the polling interval adapts while a transaction is pending.
+///
+/// Long manual approvals can take minutes, while automated flows complete in
+/// seconds. A strategy other than [`PollStrategy::Fixed`] lets the same
+/// signer confirm fast flows quickly without hammering the API during
+/// approvals. Any strategy other than `Fixed` makes polling proceed in
+/// slices of one interval.
+///
+/// # Examples
+///
+/// ```
+/// use {
+///     fireblocks_solana_signer::{PollConfig, PollStrategy},
+///     std::time::Duration,
+/// };
+///
+/// let poll = PollConfig::builder()
+///     .timeout(Duration::from_secs(900))
+///     .interval(Duration::from_secs(3))
+///     .strategy(PollStrategy::StatusAware {
+///         fast: Duration::from_secs(1),
+///         slow: Duration::from_secs(30),
+///     })
+///     .callback(|_| {})
+///     .build();
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PollStrategy {
+    /// Poll every [`PollConfig::interval`].
+    #[default]
+    Fixed,
+
+    /// Start at `initial` and double the interval after each poll, up to
+    /// `max`. Each interval is shortened by a random jitter of up to 20%, so
+    /// signers started together do not poll in lockstep.
+    Exponential { initial: Duration, max: Duration },
+
+    /// Poll every `fast` while `BROADCASTING`, every `slow` while waiting on
+    /// a human approval (`PENDING_AUTHORIZATION` or
+    /// `PENDING_3RD_PARTY_MANUAL_APPROVAL`), and every
+    /// [`PollConfig::interval`] otherwise.
+    StatusAware { fast: Duration, slow: Duration },
+}
+
+impl PollStrategy {
+    /// The interval before the first status is known.
+    pub(crate) fn first(&self, interval: Duration) -> Duration {
+        match self {
+            PollStrategy::Exponential { initial, .. } => jitter(*initial),
+            PollStrategy::Fixed | PollStrategy::StatusAware { .. } => interval,
+        }
+    }
+
+    /// The interval following `previous`, with the transaction in `status`.
+    pub(crate) fn next(
+        &self,
+        previous: Duration,
+        status: &TransactionStatus,
+        interval: Duration,
+    ) -> Duration {
+        match self {
+            PollStrategy::Fixed => interval,
+            PollStrategy::Exponential { max, .. } => jitter(previous.saturating_mul(2).min(*max)),
+            PollStrategy::StatusAware { fast, slow } => match status {
+                TransactionStatus::Broadcasting => *fast,
+                TransactionStatus::PendingAuthorization
+                | TransactionStatus::Pending3RdPartyManualApproval => *slow,
+                _ => interval,
+            },
+        }
+    }
+}
+
+/// Shortens `interval` by a random amount of up to 20%.
+fn jitter(interval: Duration) -> Duration {
+    use std::hash::BuildHasher;
+    let random = std::collections::hash_map::RandomState::new().hash_one(Instant::now());
+    interval.mul_f64(1.0 - (random % 1_000) as f64 / 5_000.0)
+}
+
+/// A shareable closure over poll responses, see [`PollConfig::on_update`],
+/// [`PollConfig::on_blocked`], [`PollConfig::on_slow`] and
+/// [`PollConfig::on_broadcasting`].
+///
+/// # Examples
+///
+/// ```
+/// use {
+///     fireblocks_solana_signer::{PollConfig, TransactionResponse},
+///     std::{sync::mpsc, time::Duration},
+/// };
+///
+/// let (tx, rx) = mpsc::channel();
+/// let poll = PollConfig::builder()
+///     .timeout(Duration::from_secs(60))
+///     .interval(Duration::from_secs(5))
+///     .callback(|_| {})
+///     .on_update(move |r: &TransactionResponse| {
+///         let _ = tx.send(r.status.to_string());
+///     })
+///     .build();
+/// ```
+#[derive(Clone)]
+pub struct PollHook(Arc<dyn Fn(&TransactionResponse) + Send + Sync>);
+
+impl PollHook {
+    /// Runs the hook with `response`.
+    pub fn call(&self, response: &TransactionResponse) {
+        (self.0)(response)
+    }
+}
+
+impl<F> From<F> for PollHook
+where
+    F: Fn(&TransactionResponse) + Send + Sync + 'static,
+{
+    fn from(f: F) -> Self {
+        Self(Arc::new(f))
+    }
+}
+
+impl Debug for PollHook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("PollHook")
+    }
+}
+
+/// Verbosity of the built-in poll callbacks.
+///
+/// Every level logs each poll tick under the `fireblocks::poll` tracing
+/// target, so it can also be filtered independently of the rest of the
+/// crate, e.g. `RUST_LOG=info,fireblocks::poll=warn`. Selected from the
+/// environment with `FIREBLOCKS_POLL_LOG` by
+/// [`FireblocksSigner::try_from_env`](crate::FireblocksSigner::try_from_env).
+///
+/// # Examples
+///
+/// ```
+/// use {
+///     fireblocks_solana_signer::{PollConfig, PollLog},
+///     std::time::Duration,
+/// };
+///
+/// let quiet = PollConfig::builder()
+///     .timeout(Duration::from_secs(60))
+///     .interval(Duration::from_secs(5))
+///     .callback(PollLog::Debug.callback())
+///     .build();
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PollLog {
+    /// Log nothing.
+    Silent,
+    Debug,
+    #[default]
+    Info,
+    Warn,
+}
+
+impl PollLog {
+    /// The poll callback logging at this level.
+    pub fn callback(self) -> fn(&crate::TransactionResponse) {
+        match self {
+            PollLog::Silent => |_| {},
+            PollLog::Debug => |t| tracing::debug!(target: "fireblocks::poll", "{t}"),
+            PollLog::Info => |t| tracing::info!(target: "fireblocks::poll", "{t}"),
+            PollLog::Warn => |t| tracing::warn!(target: "fireblocks::poll", "{t}"),
+        }
+    }
+}
+
+impl std::str::FromStr for PollLog {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        match s.to_lowercase().as_str() {
+            "silent" | "off" | "none" => Ok(PollLog::Silent),
+            "debug" => Ok(PollLog::Debug),
+            "info" => Ok(PollLog::Info),
+            "warn" => Ok(PollLog::Warn),
+            _ => Err(crate::Error::Misconfigured(format!(
+                "unknown poll log level {s}, expected silent, debug, info or warn"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_poll_strategy() {
+        let base = Duration::from_secs(5);
+        let exponential = PollStrategy::Exponential {
+            initial: Duration::from_secs(1),
+            max: Duration::from_secs(8),
+        };
+        let first = exponential.first(base);
+        assert!(first <= Duration::from_secs(1) && first >= Duration::from_millis(800));
+        let capped = exponential.next(Duration::from_secs(6), &TransactionStatus::Queued, base);
+        assert!(capped <= Duration::from_secs(8) && capped >= Duration::from_millis(6_400));
+
+        let aware = PollStrategy::StatusAware {
+            fast: Duration::from_secs(1),
+            slow: Duration::from_secs(30),
+        };
+        let next = |status| aware.next(base, &status, base);
+        assert_eq!(
+            next(TransactionStatus::Broadcasting),
+            Duration::from_secs(1)
+        );
+        assert_eq!(
+            next(TransactionStatus::PendingAuthorization),
+            Duration::from_secs(30)
+        );
+        assert_eq!(next(TransactionStatus::Queued), base);
+        assert_eq!(PollStrategy::Fixed.first(base), base);
+    }
 }
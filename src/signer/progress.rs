@@ -0,0 +1,161 @@
+use {
+    super::PollConfig,
+    crate::TransactionResponse,
+    std::{
+        cell::{Cell, RefCell},
+        fmt::Debug,
+        sync::Arc,
+        time::{Duration, Instant},
+    },
+};
+
+/// Where a sign is when a [`PollEvent`] is emitted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PollPhase {
+    /// The transaction was created at Fireblocks; there is no response yet.
+    Submitted,
+
+    /// Polling saw a status different from the previous one.
+    StatusChanged,
+
+    /// Polling ended, with a final status or at the timeout.
+    Final,
+}
+
+/// Progress of one Fireblocks transaction, passed to the
+/// [`PollConfig::on_submitted`], [`PollConfig::on_status_change`] and
+/// [`PollConfig::on_final`] hooks.
+#[derive(Clone, Copy, Debug)]
+pub struct PollEvent<'a> {
+    /// The Fireblocks transaction id.
+    pub fireblocks_id: &'a str,
+
+    pub phase: PollPhase,
+
+    /// How many responses the signer has seen for this transaction.
+    ///
+    /// Polling only hands intermediate responses to the signer when it runs
+    /// in slices, i.e. with [`PollConfig::on_status_change`],
+    /// [`PollConfig::on_update`] or [`PollConfig::cancel`] set.
+    pub attempt: u32,
+
+    /// Time since the transaction was created.
+    pub elapsed: Duration,
+
+    /// The latest response, `None` for [`PollPhase::Submitted`].
+    pub response: Option<&'a TransactionResponse>,
+}
+
+/// A shareable closure over [`PollEvent`]s.
+///
+/// # Examples
+///
+/// ```
+/// use {
+///     fireblocks_solana_signer::{PollConfig, PollEvent},
+///     std::time::Duration,
+/// };
+///
+/// let poll = PollConfig::builder()
+///     .timeout(Duration::from_secs(600))
+///     .interval(Duration::from_secs(5))
+///     .callback(|_| {})
+///     .on_status_change(|e: &PollEvent| {
+///         if let Some(r) = e.response {
+///             println!("{} is {} after {:?}", e.fireblocks_id, r.status, e.elapsed);
+///         }
+///     })
+///     .build();
+/// ```
+#[derive(Clone)]
+pub struct PollEventHook(Arc<dyn Fn(&PollEvent<'_>) + Send + Sync>);
+
+impl PollEventHook {
+    /// Runs the hook with `event`.
+    pub fn call(&self, event: &PollEvent<'_>) {
+        (self.0)(event)
+    }
+}
+
+impl<F> From<F> for PollEventHook
+where
+    F: Fn(&PollEvent<'_>) + Send + Sync + 'static,
+{
+    fn from(f: F) -> Self {
+        Self(Arc::new(f))
+    }
+}
+
+impl Debug for PollEventHook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("PollEventHook")
+    }
+}
+
+/// Tracks the responses seen while polling one transaction and emits the
+/// matching events.
+pub(crate) struct Progress {
+    id: String,
+    started: Instant,
+    attempt: Cell<u32>,
+    last_status: RefCell<Option<String>>,
+}
+
+impl Progress {
+    /// Starts tracking `id`, emitting [`PollPhase::Submitted`].
+    pub(crate) fn submitted(id: String, poll: &PollConfig) -> Self {
+        let progress = Self {
+            id,
+            started: Instant::now(),
+            attempt: Cell::new(0),
+            last_status: RefCell::new(None),
+        };
+        progress.emit(&poll.on_submitted, PollPhase::Submitted, None);
+        progress
+    }
+
+    /// The Fireblocks transaction id.
+    pub(crate) fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Records a polled response, passing it to
+    /// [`PollConfig::on_update`] and, if its status changed,
+    /// [`PollConfig::on_status_change`].
+    pub(crate) fn observe(&self, poll: &PollConfig, response: &TransactionResponse) {
+        self.attempt.set(self.attempt.get() + 1);
+        poll.notify(response);
+        let status = response.status.to_string();
+        let changed = self.last_status.borrow().as_deref() != Some(status.as_str());
+        if changed {
+            *self.last_status.borrow_mut() = Some(status);
+            self.emit(
+                &poll.on_status_change,
+                PollPhase::StatusChanged,
+                Some(response),
+            );
+        }
+    }
+
+    /// Emits [`PollPhase::Final`] for the response polling ended with.
+    pub(crate) fn finish(&self, poll: &PollConfig, response: &TransactionResponse) {
+        self.emit(&poll.on_final, PollPhase::Final, Some(response));
+    }
+
+    fn emit(
+        &self,
+        hook: &Option<PollEventHook>,
+        phase: PollPhase,
+        response: Option<&TransactionResponse>,
+    ) {
+        if let Some(hook) = hook {
+            hook.call(&PollEvent {
+                fireblocks_id: &self.id,
+                phase,
+                attempt: self.attempt.get(),
+                elapsed: self.started.elapsed(),
+                response,
+            });
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use {
+    crate::{TransactionResponse, TransactionStatus},
+    solana_sdk::signature::Signature,
+};
+
+/// The outcome of a successful Fireblocks signing request.
+///
+/// Unlike the bare [`Signature`] returned through the [`Signer`] trait, a
+/// receipt keeps the Fireblocks transaction id, which is what the Fireblocks
+/// console and support work with.
+///
+/// [`Signer`]: solana_sdk::signature::Signer
+#[derive(Debug)]
+pub struct SigningReceipt {
+    /// The Fireblocks transaction id.
+    pub fireblocks_id: String,
+
+    /// The Solana signature produced by the vault.
+    pub signature: Signature,
+
+    /// The transaction hash Fireblocks reported, if any.
+    ///
+    /// Fireblocks sets it once it broadcasts the transaction, so it is
+    /// usually `None` for sign-only transactions.
+    pub tx_hash: Option<String>,
+
+    /// The Fireblocks status the transaction ended in.
+    pub status: TransactionStatus,
+
+    /// The Fireblocks sub-status, if any.
+    pub sub_status: Option<String>,
+
+    /// The final Fireblocks transaction response.
+    ///
+    /// Keep it with audit records: besides the status it carries the approval
+    /// metadata Fireblocks returned for the transaction, such as who created,
+    /// signed or rejected it.
+    pub response: TransactionResponse,
+
+    /// How many Fireblocks transactions were created to get this signature,
+    /// see [`FireblocksSigner::create_retries`](crate::FireblocksSigner::create_retries).
+    pub attempts: u32,
+}
@@ -0,0 +1,117 @@
+use {
+    crate::TransactionResponse,
+    solana_sdk::{hash::Hash, signature::Signature},
+    std::{
+        collections::VecDeque,
+        sync::{Mutex, PoisonError},
+    },
+};
+
+/// How many final responses a signer remembers by default, see
+/// [`FireblocksSigner::recent_capacity`](crate::FireblocksSigner::recent_capacity).
+pub const DEFAULT_RECENT_CAPACITY: usize = 64;
+
+/// The final Fireblocks responses of the latest signs, most recently used
+/// first, shared by every clone of a signer.
+#[derive(Debug, Default)]
+pub(crate) struct Recent {
+    entries: Mutex<VecDeque<(Hash, TransactionResponse)>>,
+    /// Signatures and their Fireblocks ids, newest first.
+    signatures: Mutex<VecDeque<(Signature, String)>>,
+}
+
+impl Recent {
+    /// Remembers `response` for the message hashed to `message_hash`, evicting
+    /// the least recently used entries beyond `capacity`.
+    pub(crate) fn record(
+        &self,
+        message_hash: Hash,
+        response: &TransactionResponse,
+        capacity: usize,
+    ) {
+        if capacity == 0 {
+            return;
+        }
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        entries.retain(|(_, r)| r.id != response.id);
+        entries.push_front((message_hash, response.clone()));
+        entries.truncate(capacity);
+    }
+
+    /// Remembers that the Fireblocks transaction `id` produced `signature`,
+    /// keeping at most `capacity` signatures.
+    pub(crate) fn record_signature(&self, signature: Signature, id: &str, capacity: usize) {
+        if capacity == 0 {
+            return;
+        }
+        let mut signatures = self
+            .signatures
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        signatures.push_front((signature, id.to_string()));
+        signatures.truncate(capacity);
+    }
+
+    /// The Fireblocks id of the latest signature.
+    pub(crate) fn last_id(&self) -> Option<String> {
+        self.signatures
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .front()
+            .map(|(_, id)| id.clone())
+    }
+
+    /// The Fireblocks id that produced `signature`.
+    pub(crate) fn id_for(&self, signature: &Signature) -> Option<String> {
+        self.signatures
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .find(|(s, _)| s == signature)
+            .map(|(_, id)| id.clone())
+    }
+
+    /// Every remembered response, most recently used first.
+    pub(crate) fn all(&self) -> Vec<TransactionResponse> {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .map(|(_, r)| r.clone())
+            .collect()
+    }
+
+    /// The first response matching `matches`, marking it as recently used.
+    pub(crate) fn find(
+        &self,
+        matches: impl Fn(&Hash, &TransactionResponse) -> bool,
+    ) -> Option<TransactionResponse> {
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        let position = entries.iter().position(|(h, r)| matches(h, r))?;
+        let entry = entries.remove(position)?;
+        let response = entry.1.clone();
+        entries.push_front(entry);
+        Some(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signature_registry() {
+        let recent = Recent::default();
+        let (first, second, third) = (
+            Signature::new_unique(),
+            Signature::new_unique(),
+            Signature::new_unique(),
+        );
+        recent.record_signature(first, "tx-1", 2);
+        recent.record_signature(second, "tx-2", 2);
+        recent.record_signature(third, "tx-3", 2);
+        assert_eq!(recent.last_id().as_deref(), Some("tx-3"));
+        assert_eq!(recent.id_for(&second).as_deref(), Some("tx-2"));
+        assert_eq!(recent.id_for(&first), None);
+    }
+}
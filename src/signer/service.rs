@@ -0,0 +1,98 @@
+use {
+    super::{FireblocksSigner, PollConfig, SigningReceipt},
+    crate::{Error, Result},
+    solana_sdk::transaction::VersionedTransaction,
+    std::{
+        future::Future,
+        pin::Pin,
+        task::{Context, Poll},
+    },
+};
+
+/// A request handled by [`SignerService`].
+#[derive(Clone, Debug)]
+pub struct SignRequest {
+    /// The transaction to sign.
+    pub transaction: VersionedTransaction,
+
+    /// Overrides the signer's [`PollConfig`] for this request.
+    pub poll_config: Option<PollConfig>,
+}
+
+impl From<VersionedTransaction> for SignRequest {
+    fn from(transaction: VersionedTransaction) -> Self {
+        Self {
+            transaction,
+            poll_config: None,
+        }
+    }
+}
+
+/// A [`tower_service::Service`] that signs [`SignRequest`]s with a
+/// [`FireblocksSigner`].
+///
+/// Lets tower middleware (timeouts, retries, concurrency limits, load
+/// shedding) be layered around Fireblocks signing in axum or tonic services.
+/// Each call runs the blocking Fireblocks flow on a background worker thread,
+/// so it never blocks the async runtime.
+///
+/// The service reports itself not ready once
+/// [`FireblocksSigner::shutdown`] was called.
+///
+/// # Feature Flag
+///
+/// Requires the `tower` feature.
+///
+/// # Examples
+///
+/// ```no_run
+/// use {
+///     fireblocks_solana_signer::{FireblocksSigner, SignRequest, SignerService},
+///     solana_sdk::transaction::VersionedTransaction,
+///     tower_service::Service,
+/// };
+///
+/// # async fn sign(tx: VersionedTransaction) -> anyhow::Result<()> {
+/// let mut service = SignerService::new(FireblocksSigner::try_from_env(None)?);
+/// std::future::poll_fn(|cx| service.poll_ready(cx)).await?;
+/// let receipt = service.call(SignRequest::from(tx)).await?;
+/// println!("{} {}", receipt.fireblocks_id, receipt.signature);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SignerService {
+    signer: FireblocksSigner,
+}
+
+impl SignerService {
+    pub fn new(signer: FireblocksSigner) -> Self {
+        Self { signer }
+    }
+
+    /// The wrapped signer.
+    pub fn signer(&self) -> &FireblocksSigner {
+        &self.signer
+    }
+}
+
+impl tower_service::Service<SignRequest> for SignerService {
+    type Error = Error;
+    type Future = Pin<Box<dyn Future<Output = Result<SigningReceipt>> + Send>>;
+    type Response = SigningReceipt;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<()>> {
+        if self.signer.lifecycle.is_closed() {
+            Poll::Ready(Err(Error::ShuttingDown))
+        } else {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    fn call(&mut self, req: SignRequest) -> Self::Future {
+        let poll = req
+            .poll_config
+            .unwrap_or_else(|| self.signer.poll_config.clone());
+        Box::pin(self.signer.sign_receipt_async(req.transaction, poll))
+    }
+}
@@ -0,0 +1,262 @@
+use {
+    super::{FireblocksSigner, PollConfig, PollStrategy, SignerOptions},
+    crate::{Asset, Result, TransactionResponse},
+    serde::{Deserialize, Serialize},
+    solana_sdk::pubkey::Pubkey,
+    std::{str::FromStr, time::Duration},
+};
+
+const fn default_poll_timeout_ms() -> u64 {
+    60_000
+}
+
+const fn default_poll_interval_ms() -> u64 {
+    5_000
+}
+
+const fn default_interactive() -> bool {
+    true
+}
+
+/// `d` in whole milliseconds, rounding a non-zero sub-millisecond duration
+/// up so it never becomes `0`.
+fn millis(d: Duration) -> u64 {
+    u64::try_from(d.as_millis().max(u128::from(!d.is_zero()))).unwrap_or(u64::MAX)
+}
+
+/// Serializable [`FireblocksSigner`] settings, without any secrets.
+///
+/// Store one per tenant (in a database, a config file, ...) and combine it
+/// with the API credentials at runtime via [`SignerConfig::build`].
+/// Durations are stored in milliseconds.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fireblocks_solana_signer::SignerConfig;
+///
+/// # fn main() -> anyhow::Result<()> {
+/// let config: SignerConfig = serde_json::from_str(
+///     r#"{"vault_id":"0","asset":"SOL_TEST","endpoint":"https://sandbox-api.fireblocks.io"}"#,
+/// )?;
+/// let api_key = std::env::var("FIREBLOCKS_API_KEY")?;
+/// let secret = std::env::var("FIREBLOCKS_SECRET")?;
+/// let signer = config.build(&api_key, secret.as_bytes(), None)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignerConfig {
+    /// The Fireblocks vault id.
+    pub vault_id: String,
+
+    /// The Fireblocks asset id (`SOL` or `SOL_TEST`).
+    pub asset: Asset,
+
+    /// The Fireblocks API endpoint.
+    pub endpoint: String,
+
+    /// The vault's pubkey. Looked up from Fireblocks when absent.
+    #[serde(default)]
+    pub pubkey: Option<String>,
+
+    /// HTTP timeout of the Fireblocks client, see
+    /// [`SignerOptions::client_timeout`]. Its default when absent.
+    #[serde(default)]
+    pub client_timeout_ms: Option<u64>,
+
+    /// See [`PollConfig::timeout`].
+    #[serde(default = "default_poll_timeout_ms")]
+    pub poll_timeout_ms: u64,
+
+    /// See [`PollConfig::interval`].
+    #[serde(default = "default_poll_interval_ms")]
+    pub poll_interval_ms: u64,
+
+    /// See [`PollConfig::strategy`].
+    #[serde(default)]
+    pub poll_strategy: PollStrategyConfig,
+
+    /// See [`PollConfig::warn_after`].
+    #[serde(default)]
+    pub warn_after_ms: Option<u64>,
+
+    /// See [`PollConfig::broadcasting_after`].
+    #[serde(default)]
+    pub broadcasting_after_ms: Option<u64>,
+
+    /// See [`FireblocksSigner::broadcast`].
+    #[serde(default)]
+    pub broadcast: bool,
+
+    /// See [`FireblocksSigner::interactive`].
+    #[serde(default = "default_interactive")]
+    pub interactive: bool,
+
+    /// See [`FireblocksSigner::create_retries`].
+    #[serde(default)]
+    pub create_retries: u8,
+}
+
+/// Serializable form of a [`PollStrategy`], with durations in milliseconds.
+///
+/// Tagged by `kind`, e.g. `{"kind":"exponential","initial_ms":500,"max_ms":
+/// 10000}`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PollStrategyConfig {
+    /// See [`PollStrategy::Fixed`].
+    #[default]
+    Fixed,
+
+    /// See [`PollStrategy::Exponential`].
+    Exponential { initial_ms: u64, max_ms: u64 },
+
+    /// See [`PollStrategy::StatusAware`].
+    StatusAware { fast_ms: u64, slow_ms: u64 },
+}
+
+impl From<PollStrategy> for PollStrategyConfig {
+    fn from(strategy: PollStrategy) -> Self {
+        match strategy {
+            PollStrategy::Fixed => Self::Fixed,
+            PollStrategy::Exponential { initial, max } => Self::Exponential {
+                initial_ms: millis(initial),
+                max_ms: millis(max),
+            },
+            PollStrategy::StatusAware { fast, slow } => Self::StatusAware {
+                fast_ms: millis(fast),
+                slow_ms: millis(slow),
+            },
+        }
+    }
+}
+
+impl From<PollStrategyConfig> for PollStrategy {
+    fn from(config: PollStrategyConfig) -> Self {
+        match config {
+            PollStrategyConfig::Fixed => Self::Fixed,
+            PollStrategyConfig::Exponential { initial_ms, max_ms } => Self::Exponential {
+                initial: Duration::from_millis(initial_ms),
+                max: Duration::from_millis(max_ms),
+            },
+            PollStrategyConfig::StatusAware { fast_ms, slow_ms } => Self::StatusAware {
+                fast: Duration::from_millis(fast_ms),
+                slow: Duration::from_millis(slow_ms),
+            },
+        }
+    }
+}
+
+impl SignerConfig {
+    /// The [`PollConfig`] these settings describe, with `callback` or the
+    /// default logging callback.
+    fn poll_config(&self, callback: Option<fn(&TransactionResponse)>) -> PollConfig {
+        PollConfig::builder()
+            .timeout(Duration::from_millis(self.poll_timeout_ms))
+            .interval(Duration::from_millis(self.poll_interval_ms))
+            .strategy(self.poll_strategy.into())
+            .callback(callback.unwrap_or(PollConfig::default().callback))
+            .maybe_warn_after(self.warn_after_ms.map(Duration::from_millis))
+            .maybe_broadcasting_after(self.broadcasting_after_ms.map(Duration::from_millis))
+            .build()
+    }
+
+    /// Builds a [`FireblocksSigner`] from these settings and the API
+    /// credentials, see [`FireblocksSigner::try_new`].
+    ///
+    /// * `api_key` - UUID of the Fireblocks API user
+    /// * `secret` - RSA private key of the API user, see
+    ///   [`secret_pem`](crate::secret_pem)
+    /// * `callback` - poll callback, the default logging callback if `None`
+    ///
+    /// # Errors
+    ///
+    /// Fails if the client cannot be built, or the pubkey is invalid or cannot
+    /// be looked up.
+    pub fn build(
+        self,
+        api_key: &str,
+        secret: &[u8],
+        callback: Option<fn(&TransactionResponse)>,
+    ) -> Result<FireblocksSigner> {
+        let options = SignerOptions::builder()
+            .endpoint(self.endpoint.clone())
+            .maybe_pubkey(self.pubkey.as_deref().map(Pubkey::from_str).transpose()?)
+            .maybe_client_timeout(self.client_timeout_ms.map(Duration::from_millis))
+            .poll_config(self.poll_config(callback))
+            .broadcast(self.broadcast)
+            .interactive(self.interactive)
+            .create_retries(self.create_retries)
+            .build();
+        FireblocksSigner::try_new(api_key, secret, self.vault_id, self.asset, options)
+    }
+}
+
+impl FireblocksSigner {
+    /// Captures this signer's settings as a [`SignerConfig`].
+    ///
+    /// The endpoint defaults to [`FIREBLOCKS_API`](crate::FIREBLOCKS_API) if
+    /// the signer was built without one. The client timeout is not known to
+    /// the signer and is left unset.
+    pub fn to_config(&self) -> SignerConfig {
+        let poll = &self.poll_config;
+        SignerConfig {
+            vault_id: self.vault_id.clone(),
+            asset: self.asset.clone(),
+            endpoint: self
+                .endpoint
+                .clone()
+                .unwrap_or_else(|| crate::FIREBLOCKS_API.to_string()),
+            pubkey: Some(self.pk.to_string()),
+            client_timeout_ms: None,
+            poll_timeout_ms: millis(poll.timeout),
+            poll_interval_ms: millis(poll.interval),
+            poll_strategy: poll.strategy.into(),
+            warn_after_ms: poll.warn_after.map(millis),
+            broadcasting_after_ms: poll.broadcasting_after.map(millis),
+            broadcast: self.broadcast,
+            interactive: self.interactive,
+            create_retries: self.create_retries,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::test_util::remote_signer};
+
+    #[test]
+    fn test_signer_config_round_trip() -> anyhow::Result<()> {
+        let pk = Pubkey::new_unique();
+        let mut signer = remote_signer(pk);
+        signer.poll_config.interval = Duration::from_millis(500);
+        signer.poll_config.warn_after = Some(Duration::from_millis(1_500));
+        signer.poll_config.strategy = PollStrategy::Exponential {
+            initial: Duration::from_millis(250),
+            max: Duration::from_secs(8),
+        };
+        let config = signer.to_config();
+        assert_eq!(config.pubkey, Some(pk.to_string()));
+
+        let json = serde_json::to_string(&config)?;
+        assert!(json.contains("\"asset\":\"SOL_TEST\""));
+        assert!(json.contains("\"kind\":\"exponential\""));
+        assert_eq!(serde_json::from_str::<SignerConfig>(&json)?, config);
+
+        let poll = config.poll_config(None);
+        assert_eq!(poll.timeout, signer.poll_config.timeout);
+        assert_eq!(poll.interval, Duration::from_millis(500));
+        assert_eq!(poll.warn_after, Some(Duration::from_millis(1_500)));
+        assert_eq!(poll.strategy, signer.poll_config.strategy);
+        assert_eq!(millis(Duration::from_micros(10)), 1);
+
+        let minimal: SignerConfig =
+            serde_json::from_str(r#"{"vault_id":"1","asset":"sol","endpoint":"http://x"}"#)?;
+        assert_eq!(minimal.asset, crate::SOL);
+        assert_eq!(minimal.poll_timeout_ms, 60_000);
+        assert_eq!(minimal.poll_strategy, PollStrategyConfig::Fixed);
+        assert!(minimal.interactive);
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+use {
+    super::FireblocksSigner,
+    crate::Error,
+    std::{
+        sync::mpsc::{self, RecvTimeoutError},
+        thread::JoinHandle,
+        time::Duration,
+    },
+};
+
+/// Handle to a background consistency checker started with
+/// [`FireblocksSigner::spawn_watchdog`].
+///
+/// The watchdog stops when this handle is dropped.
+#[derive(Debug)]
+pub struct Watchdog {
+    stop: Option<mpsc::Sender<()>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl Drop for Watchdog {
+    fn drop(&mut self) {
+        // Dropping the sender disconnects the channel and wakes the thread.
+        self.stop.take();
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl FireblocksSigner {
+    /// Starts a background thread that runs
+    /// [`FireblocksSigner::refresh_pubkey`] every `interval`.
+    ///
+    /// When the check fails, because the API credentials were revoked or the
+    /// vault no longer resolves to [`FireblocksSigner::pk`], the signer and
+    /// all of its clones are flagged as degraded (see
+    /// [`Health::degraded`](super::Health::degraded)) and `on_degraded` is
+    /// called once. It may capture state, such as a pager handle or a
+    /// channel. The flag is cleared as soon as a later check succeeds.
+    ///
+    /// Signing is not blocked while degraded; use
+    /// [`FireblocksSigner::health`] to take the signer out of rotation.
+    ///
+    /// # Feature Flag
+    ///
+    /// Requires the `watchdog` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use {fireblocks_solana_signer::FireblocksSigner, std::time::Duration};
+    ///
+    /// # fn main() -> anyhow::Result<()> {
+    /// let signer = FireblocksSigner::try_from_env(None)?;
+    /// let _watchdog = signer.spawn_watchdog(Duration::from_secs(300), |signer, e| {
+    ///     tracing::error!("{signer:?} degraded: {e}");
+    /// });
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn spawn_watchdog(
+        &self,
+        interval: Duration,
+        on_degraded: impl Fn(&FireblocksSigner, &Error) + Send + 'static,
+    ) -> Watchdog {
+        let (stop, stopped) = mpsc::channel::<()>();
+        let signer = self.clone();
+        let handle = std::thread::Builder::new()
+            .name("fireblocks-watchdog".to_string())
+            .spawn(move || {
+                while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(interval) {
+                    match signer.refresh_pubkey() {
+                        Ok(_) => {
+                            if let Some(reason) = signer.lifecycle.set_degraded(None) {
+                                tracing::info!("{signer:?} recovered from: {reason}");
+                            }
+                        }
+                        Err(e) => {
+                            let previous = signer.lifecycle.set_degraded(Some(e.to_string()));
+                            if previous.is_none() {
+                                tracing::error!("{signer:?} degraded: {e}");
+                                on_degraded(&signer, &e);
+                            }
+                        }
+                    }
+                }
+            })
+            .ok();
+        if handle.is_none() {
+            tracing::error!("failed to spawn fireblocks watchdog thread");
+        }
+        Watchdog {
+            stop: Some(stop),
+            handle,
+        }
+    }
+}
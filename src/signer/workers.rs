@@ -0,0 +1,102 @@
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Condvar, Mutex, PoisonError},
+    time::Duration,
+};
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// How long an idle worker waits for a job before its thread exits.
+const IDLE_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Background threads running blocking signs, shared by all clones of a
+/// signer.
+///
+/// Threads are created lazily when no worker is idle and exit after
+/// [`IDLE_TIMEOUT`] without work. Signs may block for minutes waiting on
+/// approvals, so the pool never queues a job behind a busy worker; it only
+/// saves the thread churn of services that sign continuously.
+#[derive(Debug, Default)]
+pub(crate) struct Workers {
+    state: Mutex<State>,
+    job_ready: Condvar,
+}
+
+#[derive(Default)]
+struct State {
+    /// Jobs handed to an idle worker that has not picked them up yet.
+    queue: VecDeque<Job>,
+    /// Workers waiting for a job, minus the queued jobs promised to them.
+    idle: usize,
+}
+
+impl std::fmt::Debug for State {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("State")
+            .field("queue", &self.queue.len())
+            .field("idle", &self.idle)
+            .finish()
+    }
+}
+
+impl Workers {
+    /// Runs `job` on an idle worker, or on a new one if none is idle.
+    pub(crate) fn execute(self: &Arc<Self>, job: impl FnOnce() + Send + 'static) {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        if state.idle > 0 {
+            state.idle -= 1;
+            state.queue.push_back(Box::new(job));
+            self.job_ready.notify_one();
+            return;
+        }
+        drop(state);
+        let workers = Arc::clone(self);
+        std::thread::spawn(move || {
+            job();
+            workers.work();
+        });
+    }
+
+    /// Runs queued jobs until no job arrives for [`IDLE_TIMEOUT`].
+    fn work(&self) {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        state.idle += 1;
+        loop {
+            if let Some(job) = state.queue.pop_front() {
+                drop(state);
+                job();
+                state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+                state.idle += 1;
+                continue;
+            }
+            let (next, wait) = self
+                .job_ready
+                .wait_timeout(state, IDLE_TIMEOUT)
+                .unwrap_or_else(PoisonError::into_inner);
+            state = next;
+            if wait.timed_out() && state.queue.is_empty() {
+                state.idle -= 1;
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, std::sync::mpsc};
+
+    #[test]
+    fn test_workers_reuse_threads() {
+        let workers = Arc::new(Workers::default());
+        let (tx, rx) = mpsc::channel();
+        let first = tx.clone();
+        workers.execute(move || first.send(std::thread::current().id()).unwrap());
+        let first = rx.recv().unwrap();
+        while workers.state.lock().unwrap().idle == 0 {
+            std::thread::yield_now();
+        }
+        workers.execute(move || tx.send(std::thread::current().id()).unwrap());
+        assert_eq!(rx.recv().unwrap(), first);
+    }
+}
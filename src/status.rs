@@ -0,0 +1,148 @@
+//! Classification of Fireblocks transaction statuses.
+
+use crate::{TransactionResponse, TransactionStatus};
+
+/// Whether Fireblocks is still working on a transaction in `status`, i.e.
+/// polling should continue.
+pub(crate) fn is_pending(status: &TransactionStatus) -> bool {
+    matches!(
+        status,
+        TransactionStatus::Submitted
+            | TransactionStatus::Queued
+            | TransactionStatus::Pending3RdParty
+            | TransactionStatus::PendingSignature
+            | TransactionStatus::PendingAuthorization
+            | TransactionStatus::Pending3RdPartyManualApproval
+            | TransactionStatus::PendingEnrichment
+            | TransactionStatus::PendingAmlScreening
+    )
+}
+
+/// Sub-statuses of a `FAILED` transaction that Fireblocks reports for
+/// transient problems on its side.
+const TRANSIENT_SUB_STATUSES: [&str; 2] = ["CONNECTIVITY_ERROR", "INTERNAL_ERROR"];
+
+const INSUFFICIENT_FUNDS_SUB_STATUSES: [&str; 2] =
+    ["INSUFFICIENT_FUNDS", "INSUFFICIENT_FUNDS_FOR_FEE"];
+
+const BLOCKCHAIN_SUB_STATUSES: [&str; 3] = [
+    "DROPPED_BY_BLOCKCHAIN",
+    "REJECTED_BY_BLOCKCHAIN",
+    "TX_OUTDATED",
+];
+
+/// Why a Fireblocks transaction ended without a signature.
+///
+/// Derived from the final status and sub-status by [`FailureKind::of`], so
+/// callers can decide whether to retry, alert or abort. The final response of
+/// a failed sign can be looked up with
+/// [`FireblocksSigner::recent_by_id`](crate::FireblocksSigner::recent_by_id).
+///
+/// # Examples
+///
+/// ```
+/// use fireblocks_solana_signer::{FailureKind, TransactionResponse};
+///
+/// fn should_retry(response: &TransactionResponse) -> bool {
+///     FailureKind::of(response).is_some_and(FailureKind::is_retryable)
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FailureKind {
+    /// Blocked by the transaction authorization policy, or rejected by an
+    /// approver or AML screening.
+    PolicyBlocked,
+
+    /// The vault cannot pay for the transaction.
+    InsufficientFunds,
+
+    /// A transient connectivity or internal problem at Fireblocks.
+    Connectivity,
+
+    /// The network dropped or rejected the transaction, e.g. because its
+    /// blockhash expired.
+    BlockchainRejected,
+
+    /// Cancelled before it was signed.
+    Cancelled,
+
+    /// Any other failure.
+    Other,
+}
+
+impl FailureKind {
+    /// Classifies `response`, or returns `None` if it did not fail.
+    pub fn of(response: &TransactionResponse) -> Option<Self> {
+        let sub_status = response
+            .sub_status
+            .as_ref()
+            .map(ToString::to_string)
+            .unwrap_or_default();
+        Self::classify(&response.status, &sub_status)
+    }
+
+    fn classify(status: &TransactionStatus, sub_status: &str) -> Option<Self> {
+        match status {
+            TransactionStatus::Blocked | TransactionStatus::Rejected => Some(Self::PolicyBlocked),
+            TransactionStatus::Cancelled | TransactionStatus::Cancelling => Some(Self::Cancelled),
+            TransactionStatus::Failed => Some(if TRANSIENT_SUB_STATUSES.contains(&sub_status) {
+                Self::Connectivity
+            } else if INSUFFICIENT_FUNDS_SUB_STATUSES.contains(&sub_status) {
+                Self::InsufficientFunds
+            } else if BLOCKCHAIN_SUB_STATUSES.contains(&sub_status) {
+                Self::BlockchainRejected
+            } else {
+                Self::Other
+            }),
+            _ => None,
+        }
+    }
+
+    /// Whether signing the same transaction again may succeed without any
+    /// change: only [`FailureKind::Connectivity`].
+    pub fn is_retryable(self) -> bool {
+        matches!(self, Self::Connectivity)
+    }
+
+    /// Whether the failure needs a change before signing again: funding,
+    /// policy, or a rebuilt transaction.
+    pub fn is_permanent_failure(self) -> bool {
+        !self.is_retryable()
+    }
+}
+
+/// Whether `response` failed for a transient reason, so creating the
+/// transaction again may succeed.
+pub(crate) fn is_transient_failure(response: &TransactionResponse) -> bool {
+    FailureKind::of(response).is_some_and(FailureKind::is_retryable)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_failure_kind() {
+        let kind = FailureKind::classify;
+        assert_eq!(kind(&TransactionStatus::Completed, ""), None);
+        assert_eq!(kind(&TransactionStatus::PendingSignature, ""), None);
+        assert_eq!(
+            kind(&TransactionStatus::Blocked, "BLOCKED_BY_POLICY"),
+            Some(FailureKind::PolicyBlocked)
+        );
+        assert_eq!(
+            kind(&TransactionStatus::Failed, "INSUFFICIENT_FUNDS_FOR_FEE"),
+            Some(FailureKind::InsufficientFunds)
+        );
+        assert_eq!(
+            kind(&TransactionStatus::Failed, "TX_OUTDATED"),
+            Some(FailureKind::BlockchainRejected)
+        );
+        assert_eq!(
+            kind(&TransactionStatus::Failed, "SIGNING_ERROR"),
+            Some(FailureKind::Other)
+        );
+        let transient = kind(&TransactionStatus::Failed, "CONNECTIVITY_ERROR").unwrap();
+        assert!(transient.is_retryable() && !transient.is_permanent_failure());
+    }
+}
@@ -0,0 +1,16 @@
+use {
+    crate::{FireblocksSigner, PollConfig},
+    solana_sdk::pubkey::Pubkey,
+};
+
+/// A Fireblocks-backed signer for `pk` without a client, for tests that never
+/// reach Fireblocks.
+pub(crate) fn remote_signer(pk: Pubkey) -> FireblocksSigner {
+    FireblocksSigner::builder()
+        .vault_id("0".to_string())
+        .asset(crate::SOL_TEST)
+        .pk(pk)
+        .poll_config(PollConfig::default())
+        .broadcast(false)
+        .build()
+}
@@ -0,0 +1,157 @@
+//! Helpers for receiving Fireblocks webhooks.
+//!
+//! Fireblocks retries webhook deliveries and anyone who captured a delivery
+//! can resend it. A [`ReplayGuard`] rejects deliveries that are stale or were
+//! already accepted, tracking seen deliveries in a pluggable [`NonceStore`].
+//! With the `webhook` feature, [`verify_webhook_signature`] checks that a
+//! delivery comes from Fireblocks, and a [`WebhookListener`] also wakes signs
+//! waiting on the transactions deliveries are about.
+
+#[cfg(feature = "webhook")]
+mod listener;
+#[cfg(feature = "webhook")]
+mod signature;
+use {
+    crate::{Error, Result},
+    std::{
+        collections::HashMap,
+        sync::{Mutex, PoisonError},
+        time::{Duration, SystemTime, UNIX_EPOCH},
+    },
+};
+#[cfg(feature = "webhook")]
+pub use {listener::*, signature::verify_webhook_signature};
+
+/// Records which webhook deliveries were already accepted.
+///
+/// Implement this over a shared store (e.g. Redis `SET NX PX`) when several
+/// service instances receive the same webhooks.
+pub trait NonceStore: Send + Sync {
+    /// Records `nonce` until `expires_at`, returning `false` if it was already
+    /// recorded and has not yet expired.
+    fn insert(&self, nonce: &str, expires_at: SystemTime) -> bool;
+}
+
+/// An in-process [`NonceStore`], suitable for a single service instance.
+#[derive(Debug, Default)]
+pub struct MemoryNonceStore {
+    seen: Mutex<HashMap<String, SystemTime>>,
+}
+
+impl NonceStore for MemoryNonceStore {
+    fn insert(&self, nonce: &str, expires_at: SystemTime) -> bool {
+        let mut seen = self.seen.lock().unwrap_or_else(PoisonError::into_inner);
+        let now = SystemTime::now();
+        seen.retain(|_, expiry| *expiry > now);
+        if seen.contains_key(nonce) {
+            return false;
+        }
+        seen.insert(nonce.to_string(), expires_at);
+        true
+    }
+}
+
+/// Rejects replayed or stale webhook deliveries.
+///
+/// A delivery is identified by the hash of its raw body, and its age is taken
+/// from the payload's `timestamp` field (milliseconds since the Unix epoch).
+/// Verify the delivery's signature first, then pass the same raw body to
+/// [`ReplayGuard::check`].
+///
+/// # Examples
+///
+/// ```
+/// use {fireblocks_solana_signer::ReplayGuard, std::time::Duration};
+///
+/// let guard = ReplayGuard::new(Duration::from_secs(300));
+/// # let now = std::time::SystemTime::now()
+/// #     .duration_since(std::time::UNIX_EPOCH)
+/// #     .unwrap()
+/// #     .as_millis();
+/// # let body = format!(r#"{{"type":"TRANSACTION_STATUS_UPDATED","timestamp":{now}}}"#);
+/// # let body = body.as_bytes();
+/// assert!(guard.check(body).is_ok());
+/// assert!(guard.check(body).is_err());
+/// ```
+#[derive(Debug)]
+pub struct ReplayGuard<S = MemoryNonceStore> {
+    store: S,
+    max_age: Duration,
+}
+
+impl ReplayGuard {
+    /// Creates a guard backed by a [`MemoryNonceStore`] that accepts
+    /// deliveries up to `max_age` old.
+    pub fn new(max_age: Duration) -> Self {
+        Self::with_store(MemoryNonceStore::default(), max_age)
+    }
+}
+
+impl<S: NonceStore> ReplayGuard<S> {
+    /// Creates a guard backed by `store` that accepts deliveries up to
+    /// `max_age` old.
+    pub fn with_store(store: S, max_age: Duration) -> Self {
+        Self { store, max_age }
+    }
+
+    /// Accepts `body` if it is fresh and has not been accepted before.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WebhookReplay`] if the delivery is older than
+    /// `max_age`, timestamped too far in the future, or was already accepted,
+    /// and [`Error::JsonErr`] if `body` is not a JSON webhook payload.
+    pub fn check(&self, body: &[u8]) -> Result<()> {
+        let payload: serde_json::Value = serde_json::from_slice(body)?;
+        let timestamp = payload
+            .get("timestamp")
+            .and_then(serde_json::Value::as_u64)
+            .ok_or_else(|| Error::WebhookReplay("payload has no timestamp".to_string()))?;
+        let sent_at = UNIX_EPOCH + Duration::from_millis(timestamp);
+        let now = SystemTime::now();
+        match now.duration_since(sent_at) {
+            Ok(age) if age > self.max_age => {
+                return Err(Error::WebhookReplay(format!(
+                    "delivery is {age:?} old, max is {:?}",
+                    self.max_age
+                )));
+            }
+            Err(e) if e.duration() > self.max_age => {
+                return Err(Error::WebhookReplay(format!(
+                    "delivery is timestamped {:?} in the future",
+                    e.duration()
+                )));
+            }
+            _ => {}
+        }
+        let nonce = solana_sdk::hash::hash(body).to_string();
+        if !self.store.insert(&nonce, sent_at + self.max_age) {
+            return Err(Error::WebhookReplay(format!(
+                "delivery {nonce} was already accepted"
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn body(sent_at: SystemTime) -> Vec<u8> {
+        let ms = sent_at.duration_since(UNIX_EPOCH).unwrap().as_millis();
+        format!(r#"{{"type":"TRANSACTION_STATUS_UPDATED","timestamp":{ms}}}"#).into_bytes()
+    }
+
+    #[test]
+    fn test_replay_guard() {
+        let guard = ReplayGuard::new(Duration::from_secs(60));
+        let fresh = body(SystemTime::now());
+        assert!(guard.check(&fresh).is_ok());
+        assert!(matches!(guard.check(&fresh), Err(Error::WebhookReplay(_))));
+
+        let stale = body(SystemTime::now() - Duration::from_secs(120));
+        assert!(matches!(guard.check(&stale), Err(Error::WebhookReplay(_))));
+        assert!(guard.check(b"{}").is_err());
+    }
+}
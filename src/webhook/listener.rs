@@ -0,0 +1,225 @@
+use {
+    crate::{Error, Result},
+    rsa::{pkcs1v15::VerifyingKey, sha2::Sha512},
+    std::{
+        collections::HashMap,
+        sync::{Arc, Condvar, Mutex, PoisonError},
+        time::{Duration, Instant},
+    },
+};
+
+/// Wakes in-flight signs when Fireblocks sends a transaction status webhook.
+///
+/// Wire a listener into [`PollConfig::webhook`](crate::PollConfig::webhook)
+/// and pass every webhook delivery to [`WebhookListener::handle`] from your
+/// HTTP server. A sign then waits for a notification about its transaction
+/// instead of polling at [`PollConfig::interval`](crate::PollConfig::interval),
+/// and fetches the transaction once when one arrives. In case a delivery is
+/// lost, it still polls every [`fallback`](WebhookListener::with_fallback)
+/// interval.
+///
+/// Clones share the same state.
+///
+/// # Feature Flag
+///
+/// Requires the `webhook` feature.
+///
+/// # Examples
+///
+/// ```no_run
+/// use {
+///     fireblocks_solana_signer::{PollConfig, WebhookListener},
+///     std::time::Duration,
+/// };
+///
+/// # fn main() -> anyhow::Result<()> {
+/// let listener = WebhookListener::new(&std::fs::read("fireblocks_webhook.pem")?)?;
+/// let poll = PollConfig::builder()
+///     .timeout(Duration::from_secs(300))
+///     .interval(Duration::from_secs(5))
+///     .callback(|_| {})
+///     .webhook(listener.clone())
+///     .build();
+///
+/// // in the webhook route of your HTTP server:
+/// # let (body, signature_header) = (b"{}".as_slice(), "");
+/// listener.handle(body, signature_header)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct WebhookListener {
+    key: VerifyingKey<Sha512>,
+    fallback: Duration,
+    watched: Arc<Watched>,
+}
+
+/// Transaction ids signs are waiting on, and whether a notification arrived
+/// for them.
+#[derive(Debug, Default)]
+struct Watched {
+    ids: Mutex<HashMap<String, bool>>,
+    notified: Condvar,
+}
+
+impl WebhookListener {
+    /// How often a waiting sign polls anyway, unless changed with
+    /// [`WebhookListener::with_fallback`].
+    pub const DEFAULT_FALLBACK: Duration = Duration::from_secs(30);
+
+    /// Creates a listener verifying deliveries with the Fireblocks webhook
+    /// public key of your workspace, PEM encoded.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WebhookSignature`] if `public_key_pem` is not a PEM
+    /// encoded RSA public key.
+    pub fn new(public_key_pem: &[u8]) -> Result<Self> {
+        Ok(Self {
+            key: super::signature::verifying_key(public_key_pem)?,
+            fallback: Self::DEFAULT_FALLBACK,
+            watched: Arc::default(),
+        })
+    }
+
+    /// Sets how often a waiting sign polls when no notification arrives.
+    pub fn with_fallback(mut self, fallback: Duration) -> Self {
+        self.fallback = fallback;
+        self
+    }
+
+    /// How often a waiting sign polls when no notification arrives.
+    pub fn fallback(&self) -> Duration {
+        self.fallback
+    }
+
+    /// Verifies a webhook delivery and wakes the sign waiting on the
+    /// transaction it is about, if any.
+    ///
+    /// `signature_header` is the value of the `Fireblocks-Signature` header:
+    /// a base64 RSA-SHA512 signature of the raw body.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WebhookSignature`] if the signature does not verify,
+    /// and [`Error::JsonErr`] if `body` is not a JSON webhook payload.
+    pub fn handle(&self, body: &[u8], signature_header: &str) -> Result<()> {
+        super::signature::verify(&self.key, body, signature_header)?;
+        let payload: serde_json::Value = serde_json::from_slice(body)?;
+        if let Some(id) = payload
+            .pointer("/data/id")
+            .and_then(serde_json::Value::as_str)
+        {
+            self.notify(id);
+        }
+        Ok(())
+    }
+
+    /// Marks `id` as notified if a sign is waiting on it.
+    fn notify(&self, id: &str) {
+        let mut ids = self
+            .watched
+            .ids
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Some(notified) = ids.get_mut(id) {
+            *notified = true;
+            self.watched.notified.notify_all();
+        }
+    }
+
+    /// Starts recording notifications about `id`, until the returned guard
+    /// is dropped.
+    pub(crate) fn watch(&self, id: &str) -> Watch<'_> {
+        self.watched
+            .ids
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(id.to_string(), false);
+        Watch {
+            listener: self,
+            id: id.to_string(),
+        }
+    }
+}
+
+/// Notifications about one transaction, see [`WebhookListener::watch`].
+pub(crate) struct Watch<'a> {
+    listener: &'a WebhookListener,
+    id: String,
+}
+
+impl Watch<'_> {
+    /// Waits up to `timeout` for a notification, returning whether one
+    /// arrived since the last wait.
+    pub(crate) fn wait(&self, timeout: Duration) -> bool {
+        let watched = &self.listener.watched;
+        let deadline = Instant::now() + timeout;
+        let mut ids = watched.ids.lock().unwrap_or_else(PoisonError::into_inner);
+        loop {
+            if let Some(notified) = ids.get_mut(&self.id).filter(|n| **n) {
+                *notified = false;
+                return true;
+            }
+            let left = deadline.saturating_duration_since(Instant::now());
+            if left.is_zero() {
+                return false;
+            }
+            ids = watched
+                .notified
+                .wait_timeout(ids, left)
+                .unwrap_or_else(PoisonError::into_inner)
+                .0;
+        }
+    }
+}
+
+impl Drop for Watch<'_> {
+    fn drop(&mut self) {
+        self.listener
+            .watched
+            .ids
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&self.id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        base64::prelude::*,
+        rsa::{
+            RsaPrivateKey,
+            pkcs1v15::SigningKey,
+            pkcs8::{EncodePublicKey, LineEnding},
+            signature::{SignatureEncoding, Signer},
+        },
+    };
+
+    #[test]
+    fn test_listener_wakes_watchers() -> anyhow::Result<()> {
+        let private = RsaPrivateKey::new(&mut rand::thread_rng(), 1024)?;
+        let pem = private.to_public_key().to_public_key_pem(LineEnding::LF)?;
+        let listener = WebhookListener::new(pem.as_bytes())?;
+        let body =
+            br#"{"type":"TRANSACTION_STATUS_UPDATED","data":{"id":"tx-1","status":"COMPLETED"}}"#;
+        let signature =
+            BASE64_STANDARD.encode(SigningKey::<Sha512>::new(private).sign(body).to_bytes());
+
+        let watch = listener.watch("tx-1");
+        assert!(!watch.wait(Duration::ZERO));
+        listener.handle(body, &signature)?;
+        assert!(watch.wait(Duration::from_secs(1)));
+        assert!(!watch.wait(Duration::ZERO));
+
+        assert!(matches!(
+            listener.handle(b"{}", &signature),
+            Err(Error::WebhookSignature(_))
+        ));
+        drop(watch);
+        assert!(listener.watched.ids.lock().unwrap().is_empty());
+        Ok(())
+    }
+}
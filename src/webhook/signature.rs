@@ -0,0 +1,79 @@
+use {
+    crate::{Error, Result, TransactionResponse},
+    base64::prelude::*,
+    rsa::{
+        RsaPublicKey,
+        pkcs1v15::{Signature, VerifyingKey},
+        pkcs8::DecodePublicKey,
+        sha2::Sha512,
+        signature::Verifier,
+    },
+};
+
+/// Verifies a Fireblocks transaction webhook and returns the transaction it
+/// reports.
+///
+/// * `public_key_pem` - the webhook public key of your workspace, PEM encoded
+/// * `body` - the raw request body, exactly as received
+/// * `signature_header` - the value of the `Fireblocks-Signature` header, a
+///   base64 RSA-SHA512 signature of `body`
+///
+/// Use it from any HTTP server. To also wake signs waiting on the
+/// transaction, use [`WebhookListener::handle`](crate::WebhookListener::handle)
+/// instead.
+///
+/// # Feature Flag
+///
+/// Requires the `webhook` feature.
+///
+/// # Errors
+///
+/// Returns [`Error::WebhookSignature`] if the key is invalid or the
+/// signature does not verify, and [`Error::JsonErr`] if the payload carries
+/// no transaction.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fireblocks_solana_signer::verify_webhook_signature;
+///
+/// # fn route(body: &[u8], signature_header: &str) -> anyhow::Result<()> {
+/// let key = std::fs::read("fireblocks_webhook.pem")?;
+/// let tx = verify_webhook_signature(&key, body, signature_header)?;
+/// println!("{} is now {}", tx.id, tx.status);
+/// # Ok(())
+/// # }
+/// ```
+pub fn verify_webhook_signature(
+    public_key_pem: &[u8],
+    body: &[u8],
+    signature_header: &str,
+) -> Result<TransactionResponse> {
+    verify(&verifying_key(public_key_pem)?, body, signature_header)?;
+    let mut payload: serde_json::Value = serde_json::from_slice(body)?;
+    Ok(serde_json::from_value(payload["data"].take())?)
+}
+
+/// Parses a PEM encoded RSA public key.
+pub(crate) fn verifying_key(public_key_pem: &[u8]) -> Result<VerifyingKey<Sha512>> {
+    let pem = std::str::from_utf8(public_key_pem)
+        .map_err(|e| Error::WebhookSignature(format!("public key is not PEM: {e}")))?;
+    let key = RsaPublicKey::from_public_key_pem(pem)
+        .map_err(|e| Error::WebhookSignature(format!("invalid public key: {e}")))?;
+    Ok(VerifyingKey::new(key))
+}
+
+/// Checks that `signature_header` is a valid signature of `body` by `key`.
+pub(crate) fn verify(
+    key: &VerifyingKey<Sha512>,
+    body: &[u8],
+    signature_header: &str,
+) -> Result<()> {
+    let bytes = BASE64_STANDARD
+        .decode(signature_header.trim())
+        .map_err(|e| Error::WebhookSignature(format!("signature is not base64: {e}")))?;
+    let signature = Signature::try_from(bytes.as_slice())
+        .map_err(|e| Error::WebhookSignature(e.to_string()))?;
+    key.verify(body, &signature)
+        .map_err(|_| Error::WebhookSignature("signature does not match the body".to_string()))
+}
@@ -0,0 +1,141 @@
+//! Routing across several Fireblocks workspaces.
+//!
+//! Organizations often split staging and production, or regions, into
+//! separate Fireblocks workspaces, each with its own endpoint and API user. A
+//! [`WorkspaceManager`] holds one [`Client`] per workspace and builds signers
+//! or queries transactions by workspace id.
+
+use {
+    crate::{
+        Asset,
+        Client,
+        ClientBuilder,
+        Error,
+        FireblocksSigner,
+        PollConfig,
+        ReadOnlyClient,
+        Result,
+        TransactionResponse,
+    },
+    std::collections::BTreeMap,
+};
+
+/// Fireblocks clients keyed by workspace id.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fireblocks_solana_signer::{
+///     ClientBuilder,
+///     FIREBLOCKS_API,
+///     FIREBLOCKS_SANDBOX_API,
+///     PollConfig,
+///     SOL,
+///     WorkspaceManager,
+/// };
+///
+/// # fn main() -> anyhow::Result<()> {
+/// let mut workspaces = WorkspaceManager::default();
+/// workspaces.build(
+///     "staging",
+///     ClientBuilder::new("staging-api-key", b"staging-pem").with_url(FIREBLOCKS_SANDBOX_API),
+/// )?;
+/// workspaces.build(
+///     "production",
+///     ClientBuilder::new("prod-api-key", b"prod-pem").with_url(FIREBLOCKS_API),
+/// )?;
+/// let signer = workspaces.signer("production", "0", SOL, PollConfig::default())?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct WorkspaceManager {
+    clients: BTreeMap<String, Client>,
+}
+
+impl std::fmt::Debug for WorkspaceManager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.clients.keys()).finish()
+    }
+}
+
+impl WorkspaceManager {
+    /// Registers `client` for `workspace`, returning the client it replaces.
+    pub fn insert(&mut self, workspace: impl Into<String>, client: Client) -> Option<Client> {
+        self.clients.insert(workspace.into(), client)
+    }
+
+    /// Builds a client from `builder` and registers it for `workspace`.
+    ///
+    /// See [`build_client_safe`](crate::build_client_safe).
+    pub fn build(&mut self, workspace: impl Into<String>, builder: ClientBuilder) -> Result<()> {
+        let client = crate::build_client_safe(builder)?;
+        self.insert(workspace, client);
+        Ok(())
+    }
+
+    /// The ids of all registered workspaces, in sorted order.
+    pub fn workspaces(&self) -> impl Iterator<Item = &str> {
+        self.clients.keys().map(String::as_str)
+    }
+
+    /// The client registered for `workspace`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::UnknownWorkspace`] if no client is registered.
+    pub fn client(&self, workspace: &str) -> Result<&Client> {
+        self.clients
+            .get(workspace)
+            .ok_or_else(|| Error::UnknownWorkspace(workspace.to_string()))
+    }
+
+    /// Builds a signer for `vault_id` in `workspace`, resolving its pubkey
+    /// from Fireblocks.
+    pub fn signer(
+        &self,
+        workspace: &str,
+        vault_id: impl Into<String>,
+        asset: Asset,
+        poll_config: PollConfig,
+    ) -> Result<FireblocksSigner> {
+        let client = self.client(workspace)?.clone();
+        let vault_id = vault_id.into();
+        let pk = crate::address_blocking_safe(&client, vault_id.clone(), asset.clone())?;
+        Ok(FireblocksSigner::builder()
+            .client(client)
+            .vault_id(vault_id)
+            .asset(asset)
+            .pk(pk)
+            .poll_config(poll_config)
+            .broadcast(false)
+            .build())
+    }
+
+    /// Fetches the current state of the Fireblocks transaction `id` in
+    /// `workspace`.
+    pub fn transaction(&self, workspace: &str, id: &str) -> Result<TransactionResponse> {
+        self.read_only(workspace)?.transaction(id)
+    }
+
+    /// A [`ReadOnlyClient`] for `workspace`, for handing to code that must
+    /// not create transactions.
+    pub fn read_only(&self, workspace: &str) -> Result<ReadOnlyClient> {
+        Ok(self.client(workspace)?.clone().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unknown_workspace() {
+        let workspaces = WorkspaceManager::default();
+        assert!(matches!(
+            workspaces.client("production"),
+            Err(Error::UnknownWorkspace(w)) if w == "production"
+        ));
+        assert_eq!(workspaces.workspaces().count(), 0);
+    }
+}
@@ -144,10 +144,10 @@ fn test_keypair() -> anyhow::Result<()> {
     tx.try_sign(&[&signer], hash)?;
     tracing::info!("sig {}", tx.get_signature());
 
-    let base64 = signer.to_base58_string();
+    let base64 = signer.to_base58_string()?;
     let from_base64 = FireblocksSigner::from_base58_string(&base64);
     assert_eq!(signer.pk, from_base64.pk);
-    let b = signer.to_bytes();
+    let b = signer.to_bytes()?;
     let from_b = FireblocksSigner::new_from_array(b[..32].try_into().unwrap());
     assert_eq!(signer.pk, from_b.pk);
 